@@ -0,0 +1,19 @@
+//! Benchmarks of every implemented part, run with `cargo bench`.
+//!
+//! Each part is solved several times on the real input (if present in
+//! `inputs/`) and on the synthetic one; the table reports the fastest run.
+
+use std::path::Path;
+
+use aoc2018::bench;
+
+#[global_allocator]
+static ALLOCATOR: bench::PeakAllocator = bench::PeakAllocator;
+
+const RUNS: usize = 5;
+
+fn main() {
+    let inputs_dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("inputs");
+    let measurements = bench::run_all(&inputs_dir, RUNS);
+    print!("{}", bench::format_table(&measurements));
+}
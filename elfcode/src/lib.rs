@@ -0,0 +1,461 @@
+//! Virtual machine for the "Elf code" assembly used by several puzzles
+//! (days 16, 19 and 21).
+
+#![allow(clippy::needless_return)]
+#![allow(clippy::upper_case_acronyms)]
+
+use std::error;
+use std::fmt;
+use std::ops::Index;
+use std::ops::IndexMut;
+use std::str::FromStr;
+
+pub type Value = usize;
+
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub enum ParseError {
+    InvalidFormat(String),
+    InvalidOpCode(String),
+    InvalidNumber(String),
+}
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ParseError::InvalidFormat(s) => write!(f, "invalid format: {}", s),
+            ParseError::InvalidOpCode(s) => write!(f, "invalid opcode: {}", s),
+            ParseError::InvalidNumber(s) => write!(f, "invalid number: {}", s),
+        }
+    }
+}
+impl error::Error for ParseError {}
+
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub enum VmError {
+    InvalidRegister(Value),
+    InvalidInstructionPointer(Value),
+    /// Given instruction overflowed a register or the instruction pointer.
+    Overflow(Instruction),
+}
+impl fmt::Display for VmError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            VmError::InvalidRegister(r) => write!(f, "invalid register: {}", r),
+            VmError::InvalidInstructionPointer(r) => write!(f, "invalid instruction pointer register: {}", r),
+            VmError::Overflow(instruction) => write!(f, "overflow in {}", instruction),
+        }
+    }
+}
+impl error::Error for VmError {}
+
+fn parse_value(s: &str) -> Result<Value, ParseError> {
+    match s.parse() {
+        Ok(v) => Ok(v),
+        Err(_) => Err(ParseError::InvalidNumber(s.to_string())),
+    }
+}
+
+/// Register file of a fixed width.
+#[derive(PartialEq, Eq, Hash, Clone, Debug)]
+pub struct Registers {
+    values: Vec<Value>,
+}
+impl Registers {
+    pub fn new(width: usize) -> Registers {
+        Registers { values: vec![0; width] }
+    }
+    pub fn width(&self) -> usize {
+        self.values.len()
+    }
+    pub fn get(&self, index: Value) -> Result<Value, VmError> {
+        match self.values.get(index) {
+            Some(v) => Ok(*v),
+            None => Err(VmError::InvalidRegister(index)),
+        }
+    }
+    pub fn set(&mut self, index: Value, value: Value) -> Result<(), VmError> {
+        match self.values.get_mut(index) {
+            Some(v) => {
+                *v = value;
+                Ok(())
+            },
+            None => Err(VmError::InvalidRegister(index)),
+        }
+    }
+    pub fn as_slice(&self) -> &[Value] {
+        &self.values
+    }
+}
+impl From<Vec<Value>> for Registers {
+    fn from(values: Vec<Value>) -> Self {
+        Registers { values }
+    }
+}
+impl From<&[Value]> for Registers {
+    fn from(values: &[Value]) -> Self {
+        Registers { values: values.to_vec() }
+    }
+}
+impl Index<Value> for Registers {
+    type Output = Value;
+
+    fn index(&self, index: Value) -> &Value {
+        &self.values[index]
+    }
+}
+impl IndexMut<Value> for Registers {
+    fn index_mut(&mut self, index: Value) -> &mut Value {
+        &mut self.values[index]
+    }
+}
+
+#[derive(PartialEq, Eq, Hash, Clone, Copy, Debug)]
+pub enum OpCode {
+    AddR,
+    AddI,
+    MulR,
+    MulI,
+    BAnR,
+    BAnI,
+    BOrR,
+    BOrI,
+    SetR,
+    SetI,
+    GTIR,
+    GTRI,
+    GTRR,
+    EqIR,
+    EqRI,
+    EqRR,
+}
+impl OpCode {
+    pub fn values() -> Vec<OpCode> {
+        vec![
+            OpCode::AddR, OpCode::AddI, OpCode::MulR, OpCode::MulI,
+            OpCode::BAnR, OpCode::BAnI, OpCode::BOrR, OpCode::BOrI,
+            OpCode::SetR, OpCode::SetI,
+            OpCode::GTIR, OpCode::GTRI, OpCode::GTRR,
+            OpCode::EqIR, OpCode::EqRI, OpCode::EqRR,
+        ]
+    }
+    pub fn name(&self) -> &'static str {
+        match self {
+            OpCode::AddR => "addr",
+            OpCode::AddI => "addi",
+            OpCode::MulR => "mulr",
+            OpCode::MulI => "muli",
+            OpCode::BAnR => "banr",
+            OpCode::BAnI => "bani",
+            OpCode::BOrR => "borr",
+            OpCode::BOrI => "bori",
+            OpCode::SetR => "setr",
+            OpCode::SetI => "seti",
+            OpCode::GTIR => "gtir",
+            OpCode::GTRI => "gtri",
+            OpCode::GTRR => "gtrr",
+            OpCode::EqIR => "eqir",
+            OpCode::EqRI => "eqri",
+            OpCode::EqRR => "eqrr",
+        }
+    }
+    /// Result of the operation, or `None` on overflow.
+    fn operator(&self, a: Value, b: Value) -> Option<Value> {
+        match self {
+            OpCode::AddR | OpCode::AddI => a.checked_add(b),
+            OpCode::MulR | OpCode::MulI => a.checked_mul(b),
+            OpCode::BAnR | OpCode::BAnI => Some(a & b),
+            OpCode::BOrR | OpCode::BOrI => Some(a | b),
+            OpCode::SetR | OpCode::SetI => Some(a),
+            OpCode::GTIR | OpCode::GTRI | OpCode::GTRR => Some(if a > b { 1 } else { 0 }),
+            OpCode::EqIR | OpCode::EqRI | OpCode::EqRR => Some(if a == b { 1 } else { 0 }),
+        }
+    }
+    pub fn is_a_register(&self) -> bool {
+        !matches!(self, OpCode::SetI | OpCode::GTIR | OpCode::EqIR)
+    }
+    pub fn is_b_register(&self) -> bool {
+        matches!(self,
+            OpCode::AddR | OpCode::MulR |
+            OpCode::BAnR | OpCode::BOrR |
+            OpCode::GTIR | OpCode::GTRR | OpCode::EqIR | OpCode::EqRR)
+    }
+}
+impl FromStr for OpCode {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s_lo = s.to_lowercase();
+        for candidate in OpCode::values() {
+            if s_lo == candidate.name() {
+                return Ok(candidate);
+            }
+        }
+        return Err(ParseError::InvalidOpCode(s.to_string()));
+    }
+}
+impl fmt::Display for OpCode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+#[derive(PartialEq, Eq, Hash, Clone, Copy, Debug)]
+pub struct Instruction {
+    pub op_code: OpCode,
+    pub a: Value,
+    pub b: Value,
+    pub c: Value,
+}
+impl Instruction {
+    pub fn new(op_code: OpCode, a: Value, b: Value, c: Value) -> Instruction {
+        Instruction { op_code, a, b, c }
+    }
+    /// Executes the instruction, modifying the registers in place.
+    pub fn apply(&self, reg: &mut Registers) -> Result<(), VmError> {
+        let av: Value = if self.op_code.is_a_register() { reg.get(self.a)? } else { self.a };
+        let bv: Value = if self.op_code.is_b_register() { reg.get(self.b)? } else { self.b };
+        let value = self.op_code.operator(av, bv).ok_or(VmError::Overflow(*self))?;
+        return reg.set(self.c, value);
+    }
+    /// Returns the registers after executing the instruction,
+    /// leaving the input registers untouched.
+    pub fn eval(&self, reg: &Registers) -> Result<Registers, VmError> {
+        let mut result = reg.clone();
+        self.apply(&mut result)?;
+        return Ok(result);
+    }
+}
+impl FromStr for Instruction {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let segments: Vec<&str> = s.split_whitespace().collect();
+        match *segments.as_slice() {
+            [op_code_str, a_str, b_str, c_str] => {
+                return Ok(Instruction {
+                    op_code: op_code_str.parse()?,
+                    a: parse_value(a_str)?,
+                    b: parse_value(b_str)?,
+                    c: parse_value(c_str)?,
+                });
+            },
+            _ => return Err(ParseError::InvalidFormat(s.to_string())),
+        }
+    }
+}
+impl fmt::Display for Instruction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} {} {} {}", self.op_code, self.a, self.b, self.c)
+    }
+}
+
+/// List of instructions with optional register bound to the instruction
+/// pointer (the `#ip N` directive).
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub struct Program {
+    pub ip_register: Option<Value>,
+    pub instructions: Vec<Instruction>,
+}
+impl FromStr for Program {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut ip_register = None;
+        let mut instructions = Vec::new();
+        for line in s.lines() {
+            let l = line.trim();
+            if l.is_empty() {
+                continue;
+            }
+            if l.starts_with("#ip") {
+                let segments: Vec<&str> = l.split_whitespace().collect();
+                match *segments.as_slice() {
+                    ["#ip", index_str] => {
+                        ip_register = Some(parse_value(index_str)?);
+                    },
+                    _ => return Err(ParseError::InvalidFormat(l.to_string())),
+                }
+            } else {
+                instructions.push(l.parse()?);
+            }
+        }
+        return Ok(Program { ip_register, instructions });
+    }
+}
+
+/// Machine state: the register file and the instruction pointer.
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub struct Machine {
+    pub registers: Registers,
+    pub ip: Value,
+}
+impl Machine {
+    pub fn new(width: usize) -> Machine {
+        Machine { registers: Registers::new(width), ip: 0 }
+    }
+    pub fn is_halted(&self, program: &Program) -> bool {
+        self.ip >= program.instructions.len()
+    }
+    /// Executes a single instruction. Returns `false` without doing anything
+    /// if the instruction pointer is outside of the program.
+    pub fn step(&mut self, program: &Program) -> Result<bool, VmError> {
+        if self.is_halted(program) {
+            return Ok(false);
+        }
+        let instruction = &program.instructions[self.ip];
+        match program.ip_register {
+            Some(r) => {
+                self.registers.set(r, self.ip)
+                    .map_err(|_| VmError::InvalidInstructionPointer(r))?;
+                instruction.apply(&mut self.registers)?;
+                self.ip = self.registers.get(r)?;
+            },
+            None => {
+                instruction.apply(&mut self.registers)?;
+            },
+        }
+        self.ip = self.ip.checked_add(1).ok_or(VmError::Overflow(*instruction))?;
+        return Ok(true);
+    }
+    /// Runs the program until it halts. Returns the number of executed
+    /// instructions.
+    pub fn run(&mut self, program: &Program) -> Result<usize, VmError> {
+        let mut counter = 0;
+        while self.step(program)? {
+            counter += 1;
+        }
+        return Ok(counter);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn eval(op_code: OpCode, a: Value, b: Value, registers: &[Value]) -> Result<Vec<Value>, VmError> {
+        let result = Instruction::new(op_code, a, b, 3).eval(&Registers::from(registers))?;
+        return Ok(result.as_slice().to_vec());
+    }
+
+    #[test]
+    fn arithmetic() {
+        assert_eq!(eval(OpCode::AddR, 0, 1, &[3, 4, 0, 0]), Ok(vec![3, 4, 0, 7]));
+        assert_eq!(eval(OpCode::AddI, 0, 1, &[3, 4, 0, 0]), Ok(vec![3, 4, 0, 4]));
+        assert_eq!(eval(OpCode::MulR, 0, 1, &[3, 4, 0, 0]), Ok(vec![3, 4, 0, 12]));
+        assert_eq!(eval(OpCode::MulI, 0, 5, &[3, 4, 0, 0]), Ok(vec![3, 4, 0, 15]));
+        let max = Value::MAX;
+        assert_eq!(eval(OpCode::AddI, 0, 1, &[max, 0, 0, 0]), Err(VmError::Overflow(Instruction::new(OpCode::AddI, 0, 1, 3))));
+        assert_eq!(eval(OpCode::MulR, 0, 1, &[max, 2, 0, 0]), Err(VmError::Overflow(Instruction::new(OpCode::MulR, 0, 1, 3))));
+    }
+
+    #[test]
+    fn bitwise() {
+        assert_eq!(eval(OpCode::BAnR, 0, 1, &[12, 10, 0, 0]), Ok(vec![12, 10, 0, 8]));
+        assert_eq!(eval(OpCode::BAnI, 0, 6, &[12, 10, 0, 0]), Ok(vec![12, 10, 0, 4]));
+        assert_eq!(eval(OpCode::BOrR, 0, 1, &[12, 10, 0, 0]), Ok(vec![12, 10, 0, 14]));
+        assert_eq!(eval(OpCode::BOrI, 0, 1, &[12, 10, 0, 0]), Ok(vec![12, 10, 0, 13]));
+    }
+
+    #[test]
+    fn assignment() {
+        // Operand b is ignored, even if it is no valid register.
+        assert_eq!(eval(OpCode::SetR, 1, 9, &[0, 7, 0, 0]), Ok(vec![0, 7, 0, 7]));
+        assert_eq!(eval(OpCode::SetI, 9, 9, &[0, 7, 0, 0]), Ok(vec![0, 7, 0, 9]));
+    }
+
+    #[test]
+    fn comparisons() {
+        assert_eq!(eval(OpCode::GTIR, 5, 0, &[4, 5, 0, 0]), Ok(vec![4, 5, 0, 1]));
+        assert_eq!(eval(OpCode::GTRI, 0, 4, &[4, 5, 0, 0]), Ok(vec![4, 5, 0, 0]));
+        assert_eq!(eval(OpCode::GTRR, 1, 0, &[4, 5, 0, 0]), Ok(vec![4, 5, 0, 1]));
+        assert_eq!(eval(OpCode::EqIR, 4, 0, &[4, 5, 0, 0]), Ok(vec![4, 5, 0, 1]));
+        assert_eq!(eval(OpCode::EqRI, 1, 4, &[4, 5, 0, 0]), Ok(vec![4, 5, 0, 0]));
+        assert_eq!(eval(OpCode::EqRR, 0, 1, &[4, 5, 0, 0]), Ok(vec![4, 5, 0, 0]));
+    }
+
+    #[test]
+    fn invalid_registers() {
+        assert_eq!(eval(OpCode::AddR, 4, 0, &[0; 4]), Err(VmError::InvalidRegister(4)));
+        assert_eq!(eval(OpCode::GTIR, 4, 7, &[0; 4]), Err(VmError::InvalidRegister(7)));
+        let mut registers = Registers::from(vec![0; 4]);
+        assert_eq!(Instruction::new(OpCode::SetI, 1, 0, 4).apply(&mut registers), Err(VmError::InvalidRegister(4)));
+        assert_eq!(registers.as_slice(), &[0; 4]);
+    }
+
+    #[test]
+    fn registers() {
+        let mut registers = Registers::new(3);
+        assert_eq!(registers.width(), 3);
+        registers[1] = 5;
+        assert_eq!(registers.set(2, 7), Ok(()));
+        assert_eq!(registers[2], 7);
+        assert_eq!(registers.get(1), Ok(5));
+        assert_eq!(registers.get(3), Err(VmError::InvalidRegister(3)));
+        assert_eq!(registers.set(3, 1), Err(VmError::InvalidRegister(3)));
+        assert_eq!(registers, Registers::from(&[0, 5, 7][..]));
+    }
+
+    #[test]
+    fn parse_program() {
+        let program: Program = "#ip 1\n\n  seti 5 0 1\nADDR 1 2 3\n".parse().unwrap();
+        assert_eq!(program, Program {
+            ip_register: Some(1),
+            instructions: vec![
+                Instruction::new(OpCode::SetI, 5, 0, 1),
+                Instruction::new(OpCode::AddR, 1, 2, 3),
+            ],
+        });
+        assert_eq!(program.instructions[1].to_string(), "addr 1 2 3");
+        assert_eq!("seti 5 0 1".parse::<Program>().unwrap().ip_register, None);
+    }
+
+    #[test]
+    fn parse_errors() {
+        assert_eq!("#ip".parse::<Program>(), Err(ParseError::InvalidFormat("#ip".to_string())));
+        assert_eq!("#ip x".parse::<Program>(), Err(ParseError::InvalidNumber("x".to_string())));
+        assert_eq!("seti 5 0".parse::<Program>(), Err(ParseError::InvalidFormat("seti 5 0".to_string())));
+        assert_eq!("jump 5 0 1".parse::<Program>(), Err(ParseError::InvalidOpCode("jump".to_string())));
+        assert_eq!("seti 5 -1 1".parse::<Program>(), Err(ParseError::InvalidNumber("-1".to_string())));
+    }
+
+    #[test]
+    fn ip_binding() {
+        // Day 19 example: the bound register holds the instruction pointer
+        // while an instruction runs, and jumps are writes to it.
+        let program: Program = "#ip 0\nseti 5 0 1\nseti 6 0 2\naddi 0 1 0\naddr 1 2 3\nsetr 1 0 0\nseti 8 0 4\nseti 9 0 5\n"
+            .parse().unwrap();
+        let mut machine = Machine::new(6);
+        assert_eq!(machine.step(&program), Ok(true));
+        assert_eq!((machine.ip, machine.registers.as_slice()), (1, &[0, 5, 0, 0, 0, 0][..]));
+        assert_eq!(machine.step(&program), Ok(true));
+        assert_eq!(machine.step(&program), Ok(true));
+        // addi 0 1 0 at ip 2 jumps over addr 1 2 3.
+        assert_eq!((machine.ip, machine.registers.as_slice()), (4, &[3, 5, 6, 0, 0, 0][..]));
+        assert_eq!(machine.run(&program), Ok(2));
+        assert_eq!((machine.ip, machine.registers.as_slice()), (7, &[6, 5, 6, 0, 0, 9][..]));
+    }
+
+    #[test]
+    fn halting() {
+        let program: Program = "seti 1 0 0\naddi 0 2 1\n".parse().unwrap();
+        let mut machine = Machine::new(2);
+        assert!(!machine.is_halted(&program));
+        assert_eq!(machine.run(&program), Ok(2));
+        assert!(machine.is_halted(&program));
+        assert_eq!(machine.step(&program), Ok(false));
+        assert_eq!(machine.registers.as_slice(), &[1, 3]);
+        // Jumping far past the end halts as well.
+        let jump: Program = "#ip 1\nseti 100 0 1\nseti 1 0 0\n".parse().unwrap();
+        let mut machine = Machine::new(2);
+        assert_eq!(machine.run(&jump), Ok(1));
+        assert_eq!(machine.ip, 101);
+    }
+
+    #[test]
+    fn machine_errors() {
+        let program: Program = "#ip 2\nseti 1 0 0\n".parse().unwrap();
+        assert_eq!(Machine::new(2).step(&program), Err(VmError::InvalidInstructionPointer(2)));
+        let overflow = Instruction::new(OpCode::SetI, Value::MAX, 0, 0);
+        let program = Program { ip_register: Some(0), instructions: vec![overflow] };
+        assert_eq!(Machine::new(1).run(&program), Err(VmError::Overflow(overflow)));
+    }
+}
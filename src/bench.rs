@@ -0,0 +1,275 @@
+//! Timing and memory measurements of the solvers.
+//!
+//! Peak memory is only tracked when `PeakAllocator` is registered as the
+//! global allocator of the running binary: always in `cargo bench`, and in
+//! `aoc2018` when built with the `peak-memory` feature.
+
+use std::alloc::GlobalAlloc;
+use std::alloc::Layout;
+use std::alloc::System;
+use std::fmt::Write;
+use std::fs;
+use std::path::Path;
+use std::sync::atomic::AtomicBool;
+use std::sync::atomic::AtomicUsize;
+use std::sync::atomic::Ordering;
+use std::time::Duration;
+use std::time::Instant;
+
+use crate::Answer;
+use crate::SolveError;
+
+static ACTIVE: AtomicBool = AtomicBool::new(false);
+static CURRENT: AtomicUsize = AtomicUsize::new(0);
+static PEAK: AtomicUsize = AtomicUsize::new(0);
+
+/// System allocator keeping track of the peak number of allocated bytes.
+pub struct PeakAllocator;
+
+impl PeakAllocator {
+    fn record_alloc(size: usize) {
+        ACTIVE.store(true, Ordering::Relaxed);
+        let current = CURRENT.fetch_add(size, Ordering::Relaxed) + size;
+        PEAK.fetch_max(current, Ordering::Relaxed);
+    }
+
+    fn record_dealloc(size: usize) {
+        CURRENT.fetch_sub(size, Ordering::Relaxed);
+    }
+}
+
+unsafe impl GlobalAlloc for PeakAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() {
+            Self::record_alloc(layout.size());
+        }
+        return ptr;
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc_zeroed(layout);
+        if !ptr.is_null() {
+            Self::record_alloc(layout.size());
+        }
+        return ptr;
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        Self::record_dealloc(layout.size());
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_ptr = System.realloc(ptr, layout, new_size);
+        if !new_ptr.is_null() {
+            Self::record_dealloc(layout.size());
+            Self::record_alloc(new_size);
+        }
+        return new_ptr;
+    }
+}
+
+/// Runs `f`, returning its result together with the peak number of bytes
+/// allocated on top of what was allocated before (`None` when
+/// `PeakAllocator` is not in use).
+pub fn track_peak<T>(f: impl FnOnce() -> T) -> (T, Option<usize>) {
+    let baseline = CURRENT.load(Ordering::Relaxed);
+    PEAK.store(baseline, Ordering::Relaxed);
+    let result = f();
+    let peak = PEAK.load(Ordering::Relaxed).saturating_sub(baseline);
+    if ACTIVE.load(Ordering::Relaxed) {
+        return (result, Some(peak));
+    }
+    return (result, None);
+}
+
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub enum InputKind {
+    Real,
+    Synthetic,
+}
+
+#[derive(Clone, Debug)]
+pub struct Measurement {
+    pub day: u32,
+    pub part: u32,
+    pub input_kind: InputKind,
+    pub input_len: usize,
+    /// Fastest of the runs.
+    pub elapsed: Duration,
+    /// Largest of the runs.
+    pub peak_bytes: Option<usize>,
+    pub result: Result<Answer, SolveError>,
+}
+
+/// Solves the puzzle `runs` times (at least once) on given input.
+pub fn measure(day: u32, part: u32, input_kind: InputKind, input: &str, runs: usize) -> Measurement {
+    let mut elapsed = Duration::MAX;
+    let mut peak_bytes = None;
+    let mut result = Err(SolveError::NotImplemented { day, part });
+    for _ in 0..runs.max(1) {
+        let start = Instant::now();
+        let (run_result, run_peak) = track_peak(|| crate::solve(day, part, input));
+        elapsed = elapsed.min(start.elapsed());
+        peak_bytes = peak_bytes.max(run_peak);
+        result = run_result;
+    }
+    return Measurement {
+        day,
+        part,
+        input_kind,
+        input_len: input.len(),
+        elapsed,
+        peak_bytes,
+        result,
+    };
+}
+
+/// Measures every implemented part on the real input from
+/// `inputs_dir/dayNN.txt` (when present) and on the synthetic input (when
+/// there is a generator for the day).
+pub fn run_all(inputs_dir: &Path, runs: usize) -> Vec<Measurement> {
+    let mut measurements = Vec::new();
+    for &(day, part) in crate::IMPLEMENTED_PARTS {
+        let real_path = inputs_dir.join(format!("day{:02}.txt", day));
+        match fs::read_to_string(real_path) {
+            Ok(input) => measurements.push(measure(day, part, InputKind::Real, &input, runs)),
+            Err(_) => {},
+        }
+        match synthetic_input(day) {
+            Some(input) => measurements.push(measure(day, part, InputKind::Synthetic, &input, runs)),
+            None => {},
+        }
+    }
+    return measurements;
+}
+
+fn format_bytes(bytes: usize) -> String {
+    const UNITS: [&str; 4] = ["B", "KiB", "MiB", "GiB"];
+    let mut value = bytes as f64;
+    let mut unit = 0;
+    while value >= 1024.0 && unit + 1 < UNITS.len() {
+        value /= 1024.0;
+        unit += 1;
+    }
+    if unit == 0 {
+        return format!("{} B", bytes);
+    }
+    return format!("{:.1} {}", value, UNITS[unit]);
+}
+
+/// Formats the measurements as a plain text table.
+pub fn format_table(measurements: &[Measurement]) -> String {
+    let mut table = String::new();
+    writeln!(table, "{:>3} {:>4}  {:<9} {:>10} {:>12} {:>11}  status",
+        "day", "part", "input", "size", "time", "peak mem").unwrap();
+    for m in measurements {
+        let kind = match m.input_kind {
+            InputKind::Real => "real",
+            InputKind::Synthetic => "synthetic",
+        };
+        let peak = match m.peak_bytes {
+            Some(bytes) => format_bytes(bytes),
+            None => "n/a".to_string(),
+        };
+        let status = match &m.result {
+            Ok(_) => "ok".to_string(),
+            Err(err) => err.to_string(),
+        };
+        writeln!(table, "{:>3} {:>4}  {:<9} {:>10} {:>9.3} ms {:>11}  {}",
+            m.day, m.part, kind, format_bytes(m.input_len),
+            m.elapsed.as_secs_f64() * 1000.0, peak, status).unwrap();
+    }
+    return table;
+}
+
+/// Small deterministic xorshift generator for the synthetic inputs.
+struct Rng {
+    state: u64,
+}
+impl Rng {
+    fn new(seed: u64) -> Rng {
+        Rng { state: seed.max(1) }
+    }
+    fn next(&mut self) -> u64 {
+        self.state ^= self.state << 13;
+        self.state ^= self.state >> 7;
+        self.state ^= self.state << 17;
+        return self.state;
+    }
+    /// Uniform-ish number from `0..n`.
+    fn below(&mut self, n: u64) -> u64 {
+        self.next() % n
+    }
+    fn range(&mut self, from: i64, to: i64) -> i64 {
+        from + self.below((to - from + 1) as u64) as i64
+    }
+    fn letter(&mut self) -> char {
+        (b'a' + self.below(26) as u8) as char
+    }
+}
+
+/// Generates a large input for the days that are cheap to generate. The
+/// inputs are the same on every call.
+pub fn synthetic_input(day: u32) -> Option<String> {
+    let mut rng = Rng::new(0x2018_1201 + day as u64);
+    let mut input = String::new();
+    match day {
+        1 => {
+            // Net drift of 3 guarantees that some frequency repeats.
+            let mut sum = 0;
+            for _ in 0..9999 {
+                let change = rng.range(-50, 50);
+                sum += change;
+                writeln!(input, "{:+}", change).unwrap();
+            }
+            writeln!(input, "{:+}", 3 - sum).unwrap();
+        },
+        2 => {
+            let ids: Vec<String> = (0..2000)
+                .map(|_| (0..26).map(|_| rng.letter()).collect())
+                .collect();
+            for id in &ids {
+                writeln!(input, "{}", id).unwrap();
+            }
+            // A single pair of IDs differing at one position.
+            let mut near_duplicate: Vec<char> = ids[ids.len() / 2].chars().collect();
+            near_duplicate[13] = if near_duplicate[13] == 'a' { 'b' } else { 'a' };
+            writeln!(input, "{}", near_duplicate.into_iter().collect::<String>()).unwrap();
+        },
+        3 => {
+            for id in 1..=1500 {
+                writeln!(input, "#{} @ {},{}: {}x{}", id,
+                    rng.below(970), rng.below(970), 1 + rng.below(29), 1 + rng.below(29)).unwrap();
+            }
+        },
+        5 => {
+            let mut polymer = String::new();
+            for _ in 0..50000 {
+                let c = rng.letter();
+                polymer.push(if rng.below(2) == 0 { c } else { c.to_ascii_uppercase() });
+            }
+            writeln!(input, "{}", polymer).unwrap();
+        },
+        6 => {
+            for _ in 0..50 {
+                writeln!(input, "{}, {}", rng.range(40, 360), rng.range(40, 360)).unwrap();
+            }
+        },
+        9 => {
+            writeln!(input, "430 players; last marble is worth 71588 points").unwrap();
+        },
+        11 => {
+            writeln!(input, "{}", rng.range(1000, 9999)).unwrap();
+        },
+        25 => {
+            for _ in 0..1500 {
+                let coords: Vec<String> = (0..4).map(|_| rng.range(-8, 8).to_string()).collect();
+                writeln!(input, "{}", coords.join(",")).unwrap();
+            }
+        },
+        _ => return None,
+    }
+    return Some(input);
+}
@@ -0,0 +1,234 @@
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::iter::FromIterator;
+
+use crate::parse;
+use crate::parse::ParseError;
+
+/// First frequency reached twice, with the position of the increment that
+/// led to it (both counted from 0).
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub struct Repeat {
+    pub frequency: i32,
+    pub pass: u64,
+    pub index: usize,
+}
+
+/// Device calibration fed with frequency changes one by one.
+///
+/// The final, minimum and maximum frequencies are tracked while streaming
+/// the changes (starting from 0, over a single pass of the list).
+#[derive(Clone, Debug)]
+pub struct Calibrator {
+    increments: Vec<i32>,
+    frequency: i32,
+    min_frequency: i32,
+    max_frequency: i32,
+}
+impl Calibrator {
+    pub fn new() -> Calibrator {
+        Calibrator { increments: Vec::new(), frequency: 0, min_frequency: 0, max_frequency: 0 }
+    }
+    pub fn push(&mut self, incr: i32) {
+        self.increments.push(incr);
+        self.frequency += incr;
+        self.min_frequency = self.min_frequency.min(self.frequency);
+        self.max_frequency = self.max_frequency.max(self.frequency);
+    }
+    pub fn frequency(&self) -> i32 {
+        return self.frequency;
+    }
+    pub fn min_frequency(&self) -> i32 {
+        return self.min_frequency;
+    }
+    pub fn max_frequency(&self) -> i32 {
+        return self.max_frequency;
+    }
+    /// Finds the first frequency reached twice when the changes are applied
+    /// over and over, or `None` if no frequency ever repeats.
+    pub fn first_repeat(&self) -> Option<Repeat> {
+        let n = self.increments.len() as u64;
+        return find_first_repeat(&self.increments).map(|(step, frequency)| {
+            Repeat { frequency, pass: (step - 1) / n, index: ((step - 1) % n) as usize }
+        });
+    }
+}
+impl Default for Calibrator {
+    fn default() -> Self {
+        Calibrator::new()
+    }
+}
+impl Extend<i32> for Calibrator {
+    fn extend<I: IntoIterator<Item = i32>>(&mut self, iter: I) {
+        for incr in iter {
+            self.push(incr);
+        }
+    }
+}
+impl FromIterator<i32> for Calibrator {
+    fn from_iter<I: IntoIterator<Item = i32>>(iter: I) -> Self {
+        let mut calibrator = Calibrator::new();
+        calibrator.extend(iter);
+        return calibrator;
+    }
+}
+
+/// Parses frequency changes given one per line (`+1`) or separated by
+/// commas (`+1, -2, +3`).
+pub fn parse_increments(input: &str) -> Result<Vec<i32>, ParseError> {
+    let mut increments = Vec::new();
+    for mut line in parse::lines(input) {
+        if line.is_blank() {
+            continue;
+        }
+        loop {
+            increments.push(line.integer()?);
+            line.skip_whitespace();
+            if line.rest().is_empty() {
+                break;
+            }
+            line.literal(",")?;
+        }
+    }
+    return Ok(increments);
+}
+
+/// Finds the first frequency reached twice when the increments are applied
+/// over and over, starting from 0. Returns the number of increments applied
+/// until the repeat together with the repeated frequency, or `None` if no
+/// frequency ever repeats.
+///
+/// After `k * n + j` steps (`n` increments, `0 <= j < n`) the frequency is
+/// `sums[j] + k * drift`, where `sums` are the partial sums of the first
+/// pass and `drift` is their total. Two such frequencies can only meet if
+/// their partial sums are congruent modulo the drift, so it is enough to
+/// look at the nearest partial sum in the direction of the drift within
+/// each residue class.
+pub fn find_first_repeat(increments: &[i32]) -> Option<(u64, i32)> {
+    let n = increments.len();
+    if n == 0 {
+        return None;
+    }
+    let mut sums: Vec<i64> = Vec::with_capacity(n);
+    let mut sum: i64 = 0;
+    for incr in increments {
+        sums.push(sum);
+        sum += *incr as i64;
+    }
+    let drift = sum;
+
+    if drift == 0 {
+        // Frequencies cycle with period n, so the first repeat happens
+        // within the first pass or when 0 comes back after it.
+        let mut seen: HashSet<i64> = HashSet::new();
+        for (j, s) in sums.iter().enumerate() {
+            if !seen.insert(*s) {
+                return Some((j as u64, *s as i32));
+            }
+        }
+        return Some((n as u64, 0));
+    }
+
+    // Mirror the frequencies so that the drift is positive.
+    let sign = drift.signum();
+    let drift = drift.abs();
+    let mut classes: HashMap<i64, Vec<(i64, usize)>> = HashMap::new();
+    for (j, s) in sums.iter().enumerate() {
+        let s = s * sign;
+        classes.entry(s.rem_euclid(drift)).or_default().push((s, j));
+    }
+
+    let mut best: Option<(u64, i64)> = None;
+    let mut consider = |step: u64, freq: i64| {
+        if best.is_none_or(|(best_step, _)| step < best_step) {
+            best = Some((step, freq));
+        }
+    };
+    for class in classes.values_mut() {
+        class.sort();
+        // Earliest step of the run of equal partial sums so far.
+        let mut run_start = class[0].1;
+        for w in class.windows(2) {
+            let (prev, next) = (w[0], w[1]);
+            if prev.0 == next.0 {
+                // Reached twice already within the first pass.
+                consider(next.1 as u64, next.0);
+            } else {
+                // The frequency from `run_start` reaches `next` after the
+                // given number of additional passes.
+                let passes = ((next.0 - prev.0) / drift) as u64;
+                consider(passes * n as u64 + run_start as u64, next.0);
+                run_start = next.1;
+            }
+        }
+    }
+    return best.map(|(step, freq)| (step, (freq * sign) as i32));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Straightforward simulation, bounded by `max_steps`.
+    fn simulate(increments: &[i32], max_steps: u64) -> Option<(u64, i32)> {
+        let mut seen = HashSet::new();
+        let mut freq = 0;
+        seen.insert(freq);
+        for step in 1..=max_steps {
+            freq += increments[(step as usize - 1) % increments.len()];
+            if !seen.insert(freq) {
+                return Some((step, freq));
+            }
+        }
+        return None;
+    }
+
+    #[test]
+    fn parse_formats() {
+        assert_eq!(parse_increments("+1, -2, +3, +1"), Ok(vec![1, -2, 3, 1]));
+        assert_eq!(parse_increments("+1\n-2\n+3\n+1\n"), Ok(vec![1, -2, 3, 1]));
+        assert_eq!(parse_increments("+1,-2\n\n+3, +1\n"), Ok(vec![1, -2, 3, 1]));
+        assert_eq!(parse_increments("+1 -2"), Err(ParseError::new(1, 4, "\",\"", "\"-2\"")));
+        assert_eq!(parse_increments("+1,"), Err(ParseError::new(1, 4, "integer", "end of line")));
+    }
+
+    #[test]
+    fn calibration() {
+        let calibrator: Calibrator = vec![1, -2, 3, 1].into_iter().collect();
+        assert_eq!(calibrator.frequency(), 3);
+        assert_eq!(calibrator.min_frequency(), -1);
+        assert_eq!(calibrator.max_frequency(), 3);
+        // 0 -> 1 -> -1 -> 2 -> 3 -> 4 -> 2
+        assert_eq!(calibrator.first_repeat(), Some(Repeat { frequency: 2, pass: 1, index: 1 }));
+
+        let calibrator: Calibrator = vec![1, -1].into_iter().collect();
+        assert_eq!(calibrator.first_repeat(), Some(Repeat { frequency: 0, pass: 0, index: 1 }));
+
+        assert_eq!(Calibrator::new().first_repeat(), None);
+        assert_eq!(Calibrator::from_iter(vec![1]).first_repeat(), None);
+    }
+
+    #[test]
+    fn many_passes() {
+        // Frequencies 0, 1000000, 1, 1000001, 2, ... reach 1000000 again
+        // at the start of pass 1000000.
+        assert_eq!(find_first_repeat(&[1000000, -999999]), Some((2000000, 1000000)));
+    }
+
+    #[test]
+    fn matches_simulation() {
+        let cases: [&[i32]; 8] = [
+            &[1, -2, 3, 1],
+            &[3, 3, 4, -2, -4],
+            &[-6, 3, 8, 5, -6],
+            &[7, 7, -2, -7, -4],
+            &[-3, -3, -4, 2, 4],
+            &[5, -2, -2, 4, -7, 3],
+            &[-10, 4, 3, 5, -1],
+            &[2, 2, -2, -2],
+        ];
+        for increments in cases.iter() {
+            assert_eq!(find_first_repeat(increments), simulate(increments, 10000), "{:?}", increments);
+        }
+    }
+}
@@ -0,0 +1,45 @@
+use crate::diagnostics::Diagnostics;
+use crate::parse::ParseError;
+
+use super::calibrator;
+use super::calibrator::Calibrator;
+
+pub fn solve(input: &str) -> Result<Option<i32>, ParseError> {
+    return solve_with(input, &mut Diagnostics::new());
+}
+
+/// Like `solve`, also reporting where the repeat happened.
+pub fn solve_with(input: &str, diagnostics: &mut Diagnostics) -> Result<Option<i32>, ParseError> {
+    let calibrator: Calibrator = calibrator::parse_increments(input)?.into_iter().collect();
+    let repeat_opt = calibrator.first_repeat();
+    match repeat_opt {
+        Some(repeat) => {
+            diagnostics.set("pass", repeat.pass as usize);
+            diagnostics.set("index", repeat.index);
+        },
+        None => {},
+    }
+    return Ok(repeat_opt.map(|repeat| repeat.frequency));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn examples() {
+        assert_eq!(solve("+1, -2, +3, +1"), Ok(Some(2)));
+        assert_eq!(solve("+1, -1"), Ok(Some(0)));
+        assert_eq!(solve("+3, +3, +4, -2, -4"), Ok(Some(10)));
+        assert_eq!(solve("-6, +3, +8, +5, -6"), Ok(Some(5)));
+        assert_eq!(solve("+7, +7, -2, -7, -4"), Ok(Some(14)));
+    }
+
+    #[test]
+    fn no_repeat() {
+        assert_eq!(solve(""), Ok(None));
+        assert_eq!(solve("+1\n"), Ok(None));
+        assert_eq!(solve("+1, +1"), Ok(None));
+        assert_eq!(solve("-5, +2"), Ok(None));
+    }
+}
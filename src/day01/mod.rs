@@ -0,0 +1,3 @@
+pub mod calibrator;
+pub mod first_freq_twice;
+pub mod sum;
@@ -0,0 +1,31 @@
+use crate::diagnostics::Diagnostics;
+use crate::parse::ParseError;
+
+use super::calibrator;
+use super::calibrator::Calibrator;
+
+pub fn solve(input: &str) -> Result<i32, ParseError> {
+    return solve_with(input, &mut Diagnostics::new());
+}
+
+/// Like `solve`, also reporting the frequency range.
+pub fn solve_with(input: &str, diagnostics: &mut Diagnostics) -> Result<i32, ParseError> {
+    let calibrator: Calibrator = calibrator::parse_increments(input)?.into_iter().collect();
+    diagnostics.set("min_frequency", calibrator.min_frequency());
+    diagnostics.set("max_frequency", calibrator.max_frequency());
+    return Ok(calibrator.frequency());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn examples() {
+        assert_eq!(solve("+1, -2, +3, +1"), Ok(3));
+        assert_eq!(solve("+1, +1, +1"), Ok(3));
+        assert_eq!(solve("+1, +1, -2"), Ok(0));
+        assert_eq!(solve("-1, -2, -3"), Ok(-6));
+        assert_eq!(solve("+1\n-2\n+3\n+1\n"), Ok(3));
+    }
+}
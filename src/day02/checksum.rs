@@ -0,0 +1,74 @@
+use std::collections::BTreeMap;
+use std::collections::BTreeSet;
+
+use crate::diagnostics::Diagnostics;
+
+/// Multiplicities counted by the puzzle checksum.
+pub const DEFAULT_COUNTS: [usize; 2] = [2, 3];
+
+/// Counts of every byte of a box ID, indexed by the byte itself.
+fn letter_counts(id: &str) -> [usize; 256] {
+    let mut counts = [0; 256];
+    for b in id.bytes() {
+        counts[b as usize] += 1;
+    }
+    return counts;
+}
+
+/// Set of numbers of times any letter occurs in the box ID.
+pub fn multiplicities(id: &str) -> BTreeSet<usize> {
+    return letter_counts(id).iter().cloned().filter(|&count| count > 0).collect();
+}
+
+/// Number of IDs in which some letter occurs exactly `m` times, for every
+/// multiplicity `m` occurring in any ID.
+pub fn histogram<'a>(ids: impl IntoIterator<Item = &'a str>) -> BTreeMap<usize, usize> {
+    let mut histogram = BTreeMap::new();
+    for id in ids {
+        for m in multiplicities(id) {
+            *histogram.entry(m).or_insert(0) += 1;
+        }
+    }
+    return histogram;
+}
+
+/// Product of the numbers of IDs having a letter occurring exactly `count`
+/// times, over the given counts.
+pub fn checksum(histogram: &BTreeMap<usize, usize>, counts: &[usize]) -> usize {
+    return counts.iter().map(|count| histogram.get(count).cloned().unwrap_or(0)).product();
+}
+
+pub fn solve(input: &str) -> usize {
+    return solve_with(input, &DEFAULT_COUNTS, &mut Diagnostics::new());
+}
+
+/// Like `solve` for given multiplicities, also reporting the histogram.
+pub fn solve_with(input: &str, counts: &[usize], diagnostics: &mut Diagnostics) -> usize {
+    let histogram = histogram(input.lines().map(|l| l.trim()).filter(|l| !l.is_empty()));
+    for (m, num_of_ids) in &histogram {
+        diagnostics.set(&format!("multiplicity_{}", m), *num_of_ids);
+    }
+    return checksum(&histogram, counts);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "abcdef\nbababc\nabbcde\nabcccd\naabcdd\nabcdee\nababab\n";
+
+    #[test]
+    fn example() {
+        assert_eq!(solve(EXAMPLE), 12);
+    }
+
+    #[test]
+    fn example_histogram() {
+        assert_eq!(multiplicities("bababc"), [1, 2, 3].iter().cloned().collect());
+        assert_eq!(multiplicities("ababab"), [3].iter().cloned().collect());
+        let histogram = histogram(EXAMPLE.lines());
+        assert_eq!(histogram, [(1, 6), (2, 4), (3, 3)].iter().cloned().collect());
+        assert_eq!(checksum(&histogram, &[2, 3, 1]), 72);
+        assert_eq!(checksum(&histogram, &[4]), 0);
+    }
+}
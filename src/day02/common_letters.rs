@@ -0,0 +1,65 @@
+use crate::diagnostics::Diagnostics;
+
+use super::near_duplicates;
+use super::near_duplicates::NearDuplicate;
+
+/// Mismatch budget of the puzzle: the IDs differ at exactly one position.
+pub const DEFAULT_MISMATCHES: usize = 1;
+
+fn common_letters(ids: &[&str], near_duplicate: &NearDuplicate) -> String {
+    return ids[near_duplicate.first].chars()
+        .enumerate()
+        .filter(|(i, _)| !near_duplicate.positions.contains(i))
+        .map(|(_, c)| c)
+        .collect();
+}
+
+pub fn solve(input: &str) -> Option<String> {
+    return solve_with(input, DEFAULT_MISMATCHES, &mut Diagnostics::new());
+}
+
+/// Like `solve` for IDs differing at up to `max_mismatches` positions,
+/// taking the first pair of IDs. With a budget above one, also reports the
+/// number of such pairs and traces the letters they have in common.
+pub fn solve_with(input: &str, max_mismatches: usize, diagnostics: &mut Diagnostics) -> Option<String> {
+    let ids: Vec<&str> = input.lines().map(|l| l.trim()).filter(|l| !l.is_empty()).collect();
+    if max_mismatches == 1 {
+        let near_duplicate = near_duplicates::find_near_duplicate(&ids)?;
+        return Some(common_letters(&ids, &near_duplicate));
+    }
+    let near_duplicates = near_duplicates::find_near_duplicates(&ids, max_mismatches);
+    diagnostics.set("near_duplicates", near_duplicates.len());
+    for near_duplicate in &near_duplicates {
+        diagnostics.trace(|| {
+            format!("{} {}: {}", ids[near_duplicate.first], ids[near_duplicate.second], common_letters(&ids, near_duplicate))
+        });
+    }
+    return near_duplicates.first().map(|near_duplicate| common_letters(&ids, near_duplicate));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example() {
+        let input = "abcde\nfghij\nklmno\npqrst\nfguij\naxcye\nwvxyz\n";
+        assert_eq!(solve(input), Some("fgij".to_string()));
+    }
+
+    #[test]
+    fn single_answer() {
+        // Identical IDs and IDs differing at two positions do not count.
+        let input = "abcde\nabcde\naxcye\nabxde\n";
+        assert_eq!(solve(input), Some("abde".to_string()));
+    }
+
+    #[test]
+    fn mismatch_budget() {
+        let input = "abcde\nfghij\nklmno\npqrst\nfguij\naxcye\nwvxyz\n";
+        let mut diagnostics = Diagnostics::with_trace();
+        assert_eq!(solve_with(input, 2, &mut diagnostics), Some("ace".to_string()));
+        assert_eq!(diagnostics.get("near_duplicates"), Some(&2.into()));
+        assert_eq!(diagnostics.trace_lines(), ["abcde axcye: ace", "fghij fguij: fgij"]);
+    }
+}
@@ -0,0 +1,3 @@
+pub mod checksum;
+pub mod common_letters;
+pub mod near_duplicates;
@@ -0,0 +1,153 @@
+use std::collections::BTreeSet;
+use std::collections::HashMap;
+use std::collections::HashSet;
+
+/// Pair of box IDs (indices into the input) differing at given positions.
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub struct NearDuplicate {
+    pub first: usize,
+    pub second: usize,
+    pub positions: Vec<usize>,
+}
+
+const HASH_BASE: u64 = 1_000_003;
+
+fn mismatches(id1: &[u8], id2: &[u8]) -> Vec<usize> {
+    return (0..id1.len()).filter(|&i| id1[i] != id2[i]).collect();
+}
+
+/// Finds two IDs of the same length differing at exactly one position.
+///
+/// Every ID is hashed once per position with that position masked out; the
+/// masked hashes are derived from the full polynomial hash in constant
+/// time, so the search takes O(n * L) apart from verifying hash hits.
+/// Repeats of an ID are skipped, so barring hash collisions every bucket
+/// holds a single ID until the match is found.
+/// Returns the pair whose second ID comes first in the input.
+pub fn find_near_duplicate(ids: &[&str]) -> Option<NearDuplicate> {
+    let mut seen: HashMap<(usize, usize, u64), Vec<usize>> = HashMap::new();
+    let mut distinct: HashSet<&str> = HashSet::new();
+    for (index, id) in ids.iter().enumerate() {
+        if !distinct.insert(id) {
+            continue;
+        }
+        let bytes = id.as_bytes();
+        let mut powers = Vec::with_capacity(bytes.len());
+        let mut power: u64 = 1;
+        let mut hash: u64 = 0;
+        for b in bytes {
+            powers.push(power);
+            hash = hash.wrapping_add((*b as u64).wrapping_mul(power));
+            power = power.wrapping_mul(HASH_BASE);
+        }
+        for pos in 0..bytes.len() {
+            let masked = hash.wrapping_sub((bytes[pos] as u64).wrapping_mul(powers[pos]));
+            let candidates = seen.entry((bytes.len(), pos, masked)).or_default();
+            for &other in candidates.iter() {
+                let positions = mismatches(ids[other].as_bytes(), bytes);
+                if positions == [pos] {
+                    return Some(NearDuplicate { first: other, second: index, positions });
+                }
+            }
+            candidates.push(index);
+        }
+    }
+    return None;
+}
+
+/// Finds every pair of IDs of the same length differing at 1 to `k`
+/// positions, ordered by the indices of the pair.
+///
+/// IDs are split into `k + 1` blocks; two IDs within the mismatch budget
+/// agree on at least one whole block, so only IDs sharing a block are
+/// compared. Repeats of an ID are bucketed once and get the pairs of their
+/// first occurrence.
+pub fn find_near_duplicates(ids: &[&str], k: usize) -> Vec<NearDuplicate> {
+    let mut occurrences: HashMap<&str, Vec<usize>> = HashMap::new();
+    let mut distinct: Vec<&str> = Vec::new();
+    for (index, id) in ids.iter().enumerate() {
+        let indices = occurrences.entry(id).or_default();
+        if indices.is_empty() {
+            distinct.push(id);
+        }
+        indices.push(index);
+    }
+
+    let mut blocks: HashMap<(usize, usize, &[u8]), Vec<usize>> = HashMap::new();
+    let mut pairs: BTreeSet<(usize, usize)> = BTreeSet::new();
+    for (index, id) in distinct.iter().enumerate() {
+        let bytes = id.as_bytes();
+        let len = bytes.len();
+        // IDs shorter than the number of blocks get empty blocks, shared
+        // by all IDs of that length; any two of them are within the budget
+        // anyway.
+        let num_of_blocks = k + 1;
+        for block in 0..num_of_blocks {
+            let range = (block * len / num_of_blocks)..((block + 1) * len / num_of_blocks);
+            let candidates = blocks.entry((len, block, &bytes[range])).or_default();
+            for &other in candidates.iter() {
+                pairs.insert((other, index));
+            }
+            candidates.push(index);
+        }
+    }
+
+    let mut near_duplicates = Vec::new();
+    for (first, second) in pairs {
+        let positions = mismatches(distinct[first].as_bytes(), distinct[second].as_bytes());
+        if positions.len() > k {
+            continue;
+        }
+        for &i in &occurrences[distinct[first]] {
+            for &j in &occurrences[distinct[second]] {
+                let (first, second) = if i < j { (i, j) } else { (j, i) };
+                near_duplicates.push(NearDuplicate { first, second, positions: positions.clone() });
+            }
+        }
+    }
+    near_duplicates.sort_by_key(|d| (d.first, d.second));
+    return near_duplicates;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: [&str; 7] = ["abcde", "fghij", "klmno", "pqrst", "fguij", "axcye", "wvxyz"];
+
+    #[test]
+    fn example() {
+        assert_eq!(find_near_duplicate(&EXAMPLE), Some(NearDuplicate { first: 1, second: 4, positions: vec![2] }));
+        assert_eq!(find_near_duplicates(&EXAMPLE, 1), vec![NearDuplicate { first: 1, second: 4, positions: vec![2] }]);
+        assert_eq!(find_near_duplicates(&EXAMPLE, 2), vec![
+            NearDuplicate { first: 0, second: 5, positions: vec![1, 3] },
+            NearDuplicate { first: 1, second: 4, positions: vec![2] },
+        ]);
+    }
+
+    #[test]
+    fn duplicates_and_lengths() {
+        assert_eq!(find_near_duplicate(&["abc", "abc", "abcd", "abd"]),
+            Some(NearDuplicate { first: 0, second: 3, positions: vec![2] }));
+        assert_eq!(find_near_duplicate(&["abc", "abc", "abcd"]), None);
+        assert_eq!(find_near_duplicates(&["abc", "abc", "ab"], 3), vec![]);
+        assert_eq!(find_near_duplicates(&["ab", "cd"], 3), vec![NearDuplicate { first: 0, second: 1, positions: vec![0, 1] }]);
+        assert_eq!(find_near_duplicates(&["abc", "abd", "abc"], 1), vec![
+            NearDuplicate { first: 0, second: 1, positions: vec![2] },
+            NearDuplicate { first: 1, second: 2, positions: vec![2] },
+        ]);
+    }
+
+    #[test]
+    fn many_repeats() {
+        // Comparing every repeat with all the earlier ones would take ages.
+        let mut ids = vec!["abcdefghijklmnopqrstuvwxyz"; 50_000];
+        ids.push("abcdefghijklmnopqrstuvwxyy");
+        assert_eq!(find_near_duplicate(&ids), Some(NearDuplicate { first: 0, second: 50_000, positions: vec![25] }));
+        assert_eq!(find_near_duplicates(&ids, 2).len(), 50_000);
+        assert_eq!(find_near_duplicates(&vec!["ab"; 50_000], 3), vec![]);
+        let mut short = vec!["ab"; 50_000];
+        short.push("cd");
+        assert_eq!(find_near_duplicates(&short, 3)[49_999], NearDuplicate { first: 49_999, second: 50_000, positions: vec![0, 1] });
+    }
+}
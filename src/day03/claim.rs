@@ -0,0 +1,97 @@
+use crate::parse;
+use crate::parse::LineParser;
+use crate::parse::ParseError;
+
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub struct Rect {
+    pub x: usize,
+    pub y: usize,
+    pub w: usize,
+    pub h: usize,
+}
+impl Rect {
+    pub fn area(&self) -> usize {
+        return self.w * self.h;
+    }
+    /// Common part of both rects, or `None` if they share no square inch.
+    pub fn intersection(&self, other: &Rect) -> Option<Rect> {
+        let x = self.x.max(other.x);
+        let y = self.y.max(other.y);
+        let right = (self.x + self.w).min(other.x + other.w);
+        let bottom = (self.y + self.h).min(other.y + other.h);
+        if x >= right || y >= bottom {
+            return None;
+        }
+        return Some(Rect { x, y, w: right - x, h: bottom - y });
+    }
+}
+
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub struct Claim {
+    pub id: u32,
+    pub rect: Rect,
+}
+
+/// Parses a claim in the `#123 @ 3,2: 5x4` format.
+fn parse_claim(line: &mut LineParser) -> Result<Claim, ParseError> {
+    line.skip_whitespace();
+    line.literal("#")?;
+    let id = line.integer()?;
+    line.skip_whitespace();
+    line.literal("@")?;
+    let x = line.integer()?;
+    line.literal(",")?;
+    let y = line.integer()?;
+    line.literal(":")?;
+    let w = line.integer()?;
+    line.literal("x")?;
+    let h = line.integer()?;
+    line.end()?;
+    return Ok(Claim { id, rect: Rect { x, y, w, h } });
+}
+
+pub fn parse_claims(input: &str) -> Result<Vec<Claim>, ParseError> {
+    let mut claims = Vec::new();
+    for mut line in parse::lines(input) {
+        claims.push(parse_claim(&mut line)?);
+    }
+    return Ok(claims);
+}
+
+/// Finds the indices of every pair of claims sharing some fabric, in
+/// ascending order. Claims are swept from left to right, comparing each one
+/// only with the claims still spanning its left edge.
+pub fn overlapping_pairs(claims: &[Claim]) -> Vec<(usize, usize)> {
+    let mut order: Vec<usize> = (0..claims.len()).collect();
+    order.sort_by_key(|&i| claims[i].rect.x);
+    let mut active: Vec<usize> = Vec::new();
+    let mut pairs = Vec::new();
+    for i in order {
+        let rect = claims[i].rect;
+        active.retain(|&j| claims[j].rect.x + claims[j].rect.w > rect.x);
+        for &j in &active {
+            if claims[j].rect.intersection(&rect).is_some() {
+                pairs.push((j.min(i), j.max(i)));
+            }
+        }
+        active.push(i);
+    }
+    pairs.sort();
+    return pairs;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "#1 @ 1,3: 4x4\n#2 @ 3,1: 4x4\n#3 @ 5,5: 2x2\n";
+
+    #[test]
+    fn example_claims() {
+        let claims = parse_claims(EXAMPLE).unwrap();
+        assert_eq!(claims[0], Claim { id: 1, rect: Rect { x: 1, y: 3, w: 4, h: 4 } });
+        assert_eq!(claims[0].rect.intersection(&claims[1].rect), Some(Rect { x: 3, y: 3, w: 2, h: 2 }));
+        assert_eq!(claims[0].rect.intersection(&claims[2].rect), None);
+        assert_eq!(overlapping_pairs(&claims), vec![(0, 1)]);
+    }
+}
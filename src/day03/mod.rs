@@ -0,0 +1,4 @@
+pub mod claim;
+pub mod overlap;
+pub mod report;
+pub mod unique_claim;
@@ -0,0 +1,81 @@
+use std::collections::BTreeSet;
+
+use crate::parse::ParseError;
+
+use super::claim;
+use super::claim::Rect;
+
+/// Length of the parts of a vertical line covered by at least two of the
+/// active rects.
+fn doubly_covered_length(rects: &[Rect], active: &BTreeSet<usize>) -> usize {
+    let mut events: Vec<(usize, i32)> = Vec::with_capacity(2 * active.len());
+    for &i in active {
+        events.push((rects[i].y, 1));
+        events.push((rects[i].y + rects[i].h, -1));
+    }
+    events.sort();
+    let mut length = 0;
+    let mut count = 0;
+    let mut prev_y = 0;
+    for (y, delta) in events {
+        if count >= 2 {
+            length += y - prev_y;
+        }
+        prev_y = y;
+        count += delta;
+    }
+    return length;
+}
+
+/// Area covered by at least two rects. A vertical line is swept over the x
+/// coordinates where rects start or end, so the memory used depends only on
+/// the number of rects.
+pub fn overlap_area(rects: &[Rect]) -> usize {
+    let mut events: Vec<(usize, bool, usize)> = Vec::with_capacity(2 * rects.len());
+    for (i, r) in rects.iter().enumerate() {
+        if r.area() > 0 {
+            events.push((r.x, true, i));
+            events.push((r.x + r.w, false, i));
+        }
+    }
+    events.sort();
+
+    let mut active: BTreeSet<usize> = BTreeSet::new();
+    let mut area = 0;
+    let mut prev_x = 0;
+    for (x, is_start, i) in events {
+        if x > prev_x && active.len() >= 2 {
+            area += (x - prev_x) * doubly_covered_length(rects, &active);
+        }
+        prev_x = x;
+        if is_start {
+            active.insert(i);
+        } else {
+            active.remove(&i);
+        }
+    }
+    return area;
+}
+
+pub fn solve(input: &str) -> Result<usize, ParseError> {
+    let claims = claim::parse_claims(input)?;
+    let rects: Vec<_> = claims.iter().map(|c| c.rect).collect();
+    return Ok(overlap_area(&rects));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example() {
+        let input = "#1 @ 1,3: 4x4\n#2 @ 3,1: 4x4\n#3 @ 5,5: 2x2\n";
+        assert_eq!(solve(input), Ok(4));
+    }
+
+    #[test]
+    fn large_coordinates() {
+        let input = "#1 @ 1000000000,0: 10x10\n#2 @ 1000000005,5: 10x10\n#3 @ 1000000000,0: 10x10\n";
+        assert_eq!(solve(input), Ok(100));
+    }
+}
@@ -0,0 +1,149 @@
+use std::fmt::Write;
+
+use crate::grid::Grid;
+
+use super::claim;
+use super::claim::Claim;
+
+/// Two claims sharing `area` square inches of fabric.
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub struct Conflict {
+    pub first_id: u32,
+    pub second_id: u32,
+    pub area: usize,
+}
+
+/// Every pair of overlapping claims, in input order.
+pub fn conflicts(claims: &[Claim]) -> Vec<Conflict> {
+    let mut conflicts = Vec::new();
+    for (i, j) in claim::overlapping_pairs(claims) {
+        match claims[i].rect.intersection(&claims[j].rect) {
+            Some(shared) => conflicts.push(Conflict {
+                first_id: claims[i].id,
+                second_id: claims[j].id,
+                area: shared.area(),
+            }),
+            None => {},
+        }
+    }
+    return conflicts;
+}
+
+pub fn conflicts_csv(conflicts: &[Conflict]) -> String {
+    let mut csv = String::from("first_id,second_id,shared_area\n");
+    for c in conflicts {
+        writeln!(csv, "{},{},{}", c.first_id, c.second_id, c.area).unwrap();
+    }
+    return csv;
+}
+
+/// Number of claims covering each square inch of the part of the fabric
+/// spanned by the claims, together with the position of its top-left
+/// corner.
+fn multiplicities(claims: &[Claim]) -> (Grid<i32>, (usize, usize)) {
+    let rects: Vec<_> = claims.iter().map(|c| c.rect).filter(|r| r.area() > 0).collect();
+    let left = rects.iter().map(|r| r.x).min().unwrap_or(0);
+    let top = rects.iter().map(|r| r.y).min().unwrap_or(0);
+    let right = rects.iter().map(|r| r.x + r.w).max().unwrap_or(0);
+    let bottom = rects.iter().map(|r| r.y + r.h).max().unwrap_or(0);
+
+    // Corners of every rect are marked in a difference array, which is then
+    // turned into the counts by prefix sums over both axes.
+    let mut counts = Grid::new(right - left + 1, bottom - top + 1, 0);
+    for r in &rects {
+        let (x0, y0) = (r.x - left, r.y - top);
+        let (x1, y1) = (x0 + r.w, y0 + r.h);
+        counts[(x0, y0)] += 1;
+        counts[(x1, y0)] -= 1;
+        counts[(x0, y1)] -= 1;
+        counts[(x1, y1)] += 1;
+    }
+    for y in 0..counts.height() {
+        for x in 1..counts.width() {
+            counts[(x, y)] += counts[(x - 1, y)];
+        }
+    }
+    for y in 1..counts.height() {
+        for x in 0..counts.width() {
+            counts[(x, y)] += counts[(x, y - 1)];
+        }
+    }
+    return (counts, (left, top));
+}
+
+fn multiplicity_colour(multiplicity: i32) -> image::Rgb<u8> {
+    match multiplicity {
+        0 => image::Rgb([24, 24, 24]),
+        1 => image::Rgb([60, 110, 180]),
+        2 => image::Rgb([240, 200, 40]),
+        m => {
+            let green = 100 - (25 * (m - 3)).min(100) as u8;
+            image::Rgb([220, green, 0])
+        },
+    }
+}
+
+/// Image of the fabric from its top-left corner to the farthest claim, one
+/// pixel per square inch, coloured by the number of claims covering it:
+/// dark for none, blue for one, yellow for two and increasingly deep red
+/// for more. Returns `None` if no claim covers any fabric.
+pub fn heatmap(claims: &[Claim]) -> Option<image::RgbImage> {
+    if claims.iter().all(|c| c.rect.area() == 0) {
+        return None;
+    }
+    let (counts, (left, top)) = multiplicities(claims);
+    let width = left + counts.width() - 1;
+    let height = top + counts.height() - 1;
+    let mut img = image::RgbImage::from_pixel(width as u32, height as u32, multiplicity_colour(0));
+    for y in 0..(counts.height() - 1) {
+        for x in 0..(counts.width() - 1) {
+            img.put_pixel((left + x) as u32, (top + y) as u32, multiplicity_colour(counts[(x, y)]));
+        }
+    }
+    return Some(img);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "#1 @ 1,3: 4x4\n#2 @ 3,1: 4x4\n#3 @ 5,5: 2x2\n";
+
+    #[test]
+    fn example_conflicts() {
+        let claims = claim::parse_claims(EXAMPLE).unwrap();
+        let conflicts = conflicts(&claims);
+        assert_eq!(conflicts, vec![Conflict { first_id: 1, second_id: 2, area: 4 }]);
+        assert_eq!(conflicts_csv(&conflicts), "first_id,second_id,shared_area\n1,2,4\n");
+    }
+
+    #[test]
+    fn example_heatmap() {
+        let claims = claim::parse_claims(EXAMPLE).unwrap();
+        let (counts, corner) = multiplicities(&claims);
+        assert_eq!(corner, (1, 1));
+        // Claim counts of the fabric from the statement, shifted by the corner.
+        let expected = [
+            "..1111",
+            "..1111",
+            "112211",
+            "112211",
+            "111111",
+            "111111",
+        ];
+        for (y, row) in expected.iter().enumerate() {
+            let actual: String = (0..6).map(|x| match counts[(x, y)] {
+                0 => '.',
+                n => std::char::from_digit(n as u32, 10).unwrap(),
+            }).collect();
+            assert_eq!(actual, *row, "row {}", y);
+        }
+        let img = heatmap(&claims).unwrap();
+        assert_eq!(img.dimensions(), (7, 7));
+        assert_eq!(*img.get_pixel(3, 3), multiplicity_colour(2));
+        assert_eq!(*img.get_pixel(1, 3), multiplicity_colour(1));
+        assert_eq!(*img.get_pixel(2, 2), multiplicity_colour(0));
+        assert_eq!(*img.get_pixel(0, 0), multiplicity_colour(0));
+        assert_eq!(heatmap(&[]), None);
+    }
+}
@@ -0,0 +1,30 @@
+use crate::parse::ParseError;
+
+use super::claim;
+
+pub fn solve(input: &str) -> Result<Option<u32>, ParseError> {
+    let claims = claim::parse_claims(input)?;
+
+    let mut overlapping = vec![false; claims.len()];
+    for (i, j) in claim::overlapping_pairs(&claims) {
+        overlapping[i] = true;
+        overlapping[j] = true;
+    }
+
+    return Ok(claims.iter()
+        .zip(overlapping)
+        .filter(|(c, overlaps)| !overlaps && c.rect.area() > 0)
+        .map(|(c, _)| c.id)
+        .min());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example() {
+        let input = "#1 @ 1,3: 4x4\n#2 @ 3,1: 4x4\n#3 @ 5,5: 2x2\n";
+        assert_eq!(solve(input), Ok(Some(3)));
+    }
+}
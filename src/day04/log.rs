@@ -0,0 +1,387 @@
+use std::fmt;
+
+use crate::diagnostics::Diagnostics;
+use crate::parse;
+use crate::parse::LineParser;
+use crate::parse::ParseError;
+
+#[derive(PartialEq, Eq, PartialOrd, Ord, Clone, Copy, Debug)]
+pub struct Date {
+    pub y: u32,
+    pub m: u32,
+    pub d: u32,
+}
+impl Date {
+    pub fn days_in_month(y: u32, m: u32) -> u32 {
+        match m {
+            1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
+            4 | 6 | 9 | 11 => 30,
+            2 if y.is_multiple_of(4) && (!y.is_multiple_of(100) || y.is_multiple_of(400)) => 29,
+            2 => 28,
+            _ => 0,
+        }
+    }
+    pub fn next_day(&self) -> Date {
+        if self.d < Date::days_in_month(self.y, self.m) {
+            return Date { y: self.y, m: self.m, d: self.d + 1 };
+        }
+        if self.m < 12 {
+            return Date { y: self.y, m: self.m + 1, d: 1 };
+        }
+        return Date { y: self.y + 1, m: 1, d: 1 };
+    }
+}
+impl fmt::Display for Date {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:04}-{:02}-{:02}", self.y, self.m, self.d)
+    }
+}
+
+#[derive(PartialEq, Eq, PartialOrd, Ord, Clone, Copy, Debug)]
+pub struct Time {
+    pub h: u32,
+    pub m: u32,
+}
+
+#[derive(PartialEq, Eq, PartialOrd, Ord, Clone, Copy, Debug)]
+pub struct DateTime {
+    pub date: Date,
+    pub time: Time,
+}
+
+#[derive(PartialEq, Eq, PartialOrd, Ord, Clone, Copy, Debug)]
+pub enum EventType {
+    BeginsShift,
+    FallsAsleep,
+    WakesUp,
+}
+
+/// Position of an event in the log (1-based line and columns).
+#[derive(PartialEq, Eq, PartialOrd, Ord, Clone, Copy, Debug)]
+pub struct Source {
+    pub line: usize,
+    pub date_column: usize,
+    pub time_column: usize,
+    pub event_column: usize,
+}
+
+#[derive(PartialEq, Eq, PartialOrd, Ord, Clone, Copy, Debug)]
+pub struct Event {
+    pub datetime: DateTime,
+    pub event_type: EventType,
+    pub guard_id: Option<u32>,
+    pub source: Source,
+}
+
+fn describe_event(event_type: EventType) -> &'static str {
+    match event_type {
+        EventType::BeginsShift => "\"begins shift\"",
+        EventType::FallsAsleep => "\"falls asleep\"",
+        EventType::WakesUp => "\"wakes up\"",
+    }
+}
+
+fn parse_event(line: &mut LineParser) -> Result<Event, ParseError> {
+    line.skip_whitespace();
+    line.literal("[")?;
+    line.skip_whitespace();
+    let date_column = line.column();
+    let y = line.integer()?;
+    line.literal("-")?;
+    let m = line.integer()?;
+    line.literal("-")?;
+    let d = line.integer()?;
+    line.skip_whitespace();
+    let time_column = line.column();
+    let h = line.integer()?;
+    line.literal(":")?;
+    let min = line.integer()?;
+    line.literal("]")?;
+    let datetime = DateTime {date: Date {y, m, d}, time: Time {h, m: min}};
+    line.skip_whitespace();
+    let event_start = line.clone();
+    let (event_type, guard_id) = match line.word("event")? {
+        "Guard" => {
+            line.skip_whitespace();
+            line.literal("#")?;
+            let guard_id = line.integer()?;
+            line.skip_whitespace();
+            line.literal("begins shift")?;
+            (EventType::BeginsShift, Some(guard_id))
+        },
+        "wakes" => {
+            line.skip_whitespace();
+            line.literal("up")?;
+            (EventType::WakesUp, None)
+        },
+        "falls" => {
+            line.skip_whitespace();
+            line.literal("asleep")?;
+            (EventType::FallsAsleep, None)
+        },
+        _ => return Err(event_start.error("\"Guard\", \"wakes\" or \"falls\"")),
+    };
+    line.end()?;
+    let source = Source { line: line.number(), date_column, time_column, event_column: event_start.column() };
+    return Ok(Event {datetime, event_type, guard_id, source});
+}
+
+/// Parses the log records, sorted chronologically.
+pub fn parse_events(input: &str) -> Result<Vec<Event>, ParseError> {
+    let mut events = Vec::new();
+    for mut line in parse::lines(input) {
+        events.push(parse_event(&mut line)?);
+    }
+    events.sort();
+    return Ok(events);
+}
+
+/// Minutes of the midnight hour a guard was asleep during one shift.
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub struct Shift {
+    pub guard_id: u32,
+    /// Date of the midnight hour, also for guards beginning before midnight.
+    pub date: Date,
+    pub asleep: [bool; 60],
+}
+impl Shift {
+    pub fn minutes_asleep(&self) -> usize {
+        return self.asleep.iter().filter(|a| **a).count();
+    }
+}
+
+/// Groups the sorted events into shifts. Expects events accepted by
+/// `validate`; events out of place are skipped.
+pub fn shifts(events: &[Event]) -> Vec<Shift> {
+    let mut shifts: Vec<Shift> = Vec::new();
+    let mut start_min_opt = None;
+
+    for event in events {
+        match (&event.event_type, event.guard_id) {
+            (EventType::BeginsShift, Some(guard_id)) => {
+                let date = match event.datetime.time.h {
+                    0 => event.datetime.date,
+                    _ => event.datetime.date.next_day(),
+                };
+                shifts.push(Shift { guard_id, date, asleep: [false; 60] });
+                start_min_opt = None;
+            },
+            (EventType::FallsAsleep, _) => {
+                start_min_opt = Some(event.datetime.time.m as usize);
+            },
+            (EventType::WakesUp, _) => {
+                match (shifts.last_mut(), start_min_opt) {
+                    (Some(shift), Some(start_min)) => {
+                        let end_min = (event.datetime.time.m as usize).min(60);
+                        for i in start_min..end_min {
+                            shift.asleep[i] = true;
+                        }
+                    },
+                    _ => {},
+                }
+                start_min_opt = None;
+            },
+            _ => {},
+        }
+    }
+    return shifts;
+}
+
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub enum ProblemKind {
+    InvalidDate,
+    InvalidTime,
+    SleepOutsideMidnight,
+    SleepBeforeShift,
+    DoubleSleep,
+    WakeWithoutSleep,
+    ShiftChangeWhileAsleep,
+}
+impl fmt::Display for ProblemKind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let description = match self {
+            ProblemKind::InvalidDate => "invalid date",
+            ProblemKind::InvalidTime => "invalid time",
+            ProblemKind::SleepOutsideMidnight => "sleep outside the midnight hour",
+            ProblemKind::SleepBeforeShift => "sleep before any shift",
+            ProblemKind::DoubleSleep => "falls asleep while asleep",
+            ProblemKind::WakeWithoutSleep => "wakes up without sleeping",
+            ProblemKind::ShiftChangeWhileAsleep => "shift change while asleep",
+        };
+        write!(f, "{}", description)
+    }
+}
+
+/// Inconsistency found in the log, with the original log line.
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub struct Problem {
+    pub kind: ProblemKind,
+    pub error: ParseError,
+    pub text: String,
+}
+impl fmt::Display for Problem {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "line {}: {}: {}", self.error.line, self.kind, self.text)
+    }
+}
+
+/// Checks the sorted events for dates, times and event sequences that make
+/// no sense, in chronological order.
+pub fn validate(input: &str, events: &[Event]) -> Vec<Problem> {
+    let lines: Vec<&str> = input.lines().collect();
+    let mut problems = Vec::new();
+    let mut report = |event: &Event, kind: ProblemKind, column: usize, expected: &str, found: String| {
+        problems.push(Problem {
+            kind,
+            error: ParseError::new(event.source.line, column, expected, found),
+            text: lines.get(event.source.line - 1).unwrap_or(&"").to_string(),
+        });
+    };
+
+    let mut on_shift = false;
+    let mut asleep = false;
+    for event in events {
+        let date = event.datetime.date;
+        let time = event.datetime.time;
+        if date.m < 1 || date.m > 12 || date.d < 1 || date.d > Date::days_in_month(date.y, date.m) {
+            report(event, ProblemKind::InvalidDate, event.source.date_column, "valid calendar date", format!("\"{}\"", date));
+            continue;
+        }
+        if time.h > 23 || time.m > 59 {
+            report(event, ProblemKind::InvalidTime, event.source.time_column, "time from 00:00 to 23:59", format!("\"{:02}:{:02}\"", time.h, time.m));
+            continue;
+        }
+        let found = describe_event(event.event_type).to_string();
+        match event.event_type {
+            EventType::BeginsShift => {
+                if asleep {
+                    report(event, ProblemKind::ShiftChangeWhileAsleep, event.source.event_column, "\"wakes up\"", found);
+                }
+                on_shift = true;
+                asleep = false;
+            },
+            EventType::FallsAsleep | EventType::WakesUp if time.h != 0 => {
+                report(event, ProblemKind::SleepOutsideMidnight, event.source.time_column, "time in the midnight hour", format!("\"{:02}:{:02}\"", time.h, time.m));
+            },
+            EventType::FallsAsleep => {
+                if !on_shift {
+                    report(event, ProblemKind::SleepBeforeShift, event.source.event_column, "\"Guard\" shift start", found);
+                } else if asleep {
+                    report(event, ProblemKind::DoubleSleep, event.source.event_column, "\"wakes up\"", found);
+                }
+                asleep = on_shift;
+            },
+            EventType::WakesUp => {
+                if !asleep {
+                    report(event, ProblemKind::WakeWithoutSleep, event.source.event_column, "\"falls asleep\"", found);
+                }
+                asleep = false;
+            },
+        }
+    }
+    return problems;
+}
+
+/// Parses and validates the log, tracing every problem found. The first
+/// problem is returned as the error.
+pub fn read_shifts(input: &str, diagnostics: &mut Diagnostics) -> Result<Vec<Shift>, ParseError> {
+    let events = parse_events(input)?;
+    let problems = validate(input, &events);
+    for problem in &problems {
+        diagnostics.trace(|| problem.to_string());
+    }
+    match problems.into_iter().next() {
+        Some(problem) => return Err(problem.error),
+        None => return Ok(shifts(&events)),
+    }
+}
+
+#[cfg(test)]
+pub(super) const EXAMPLE: &str = "\
+[1518-11-01 00:00] Guard #10 begins shift
+[1518-11-01 00:05] falls asleep
+[1518-11-01 00:25] wakes up
+[1518-11-01 00:30] falls asleep
+[1518-11-01 00:55] wakes up
+[1518-11-01 23:58] Guard #99 begins shift
+[1518-11-02 00:40] falls asleep
+[1518-11-02 00:50] wakes up
+[1518-11-03 00:05] Guard #10 begins shift
+[1518-11-03 00:24] falls asleep
+[1518-11-03 00:29] wakes up
+[1518-11-04 00:02] Guard #99 begins shift
+[1518-11-04 00:36] falls asleep
+[1518-11-04 00:46] wakes up
+[1518-11-05 00:03] Guard #99 begins shift
+[1518-11-05 00:45] falls asleep
+[1518-11-05 00:55] wakes up
+";
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn next_day() {
+        assert_eq!(Date { y: 1518, m: 11, d: 1 }.next_day(), Date { y: 1518, m: 11, d: 2 });
+        assert_eq!(Date { y: 1518, m: 2, d: 28 }.next_day(), Date { y: 1518, m: 3, d: 1 });
+        assert_eq!(Date { y: 1600, m: 2, d: 28 }.next_day(), Date { y: 1600, m: 2, d: 29 });
+        assert_eq!(Date { y: 1518, m: 12, d: 31 }.next_day(), Date { y: 1519, m: 1, d: 1 });
+    }
+
+    #[test]
+    fn example_shifts() {
+        let shifts = shifts(&parse_events(EXAMPLE).unwrap());
+        assert_eq!(shifts.len(), 5);
+        assert_eq!((shifts[1].guard_id, shifts[1].date), (99, Date { y: 1518, m: 11, d: 2 }));
+        assert_eq!(shifts.iter().map(|s| s.minutes_asleep()).collect::<Vec<_>>(), vec![45, 10, 5, 10, 10]);
+    }
+
+    fn problems(input: &str) -> Vec<(ProblemKind, String)> {
+        let events = parse_events(input).unwrap();
+        return validate(input, &events).into_iter().map(|p| (p.kind, p.to_string())).collect();
+    }
+
+    #[test]
+    fn example_is_valid() {
+        assert_eq!(problems(EXAMPLE), vec![]);
+    }
+
+    #[test]
+    fn problems_with_lines() {
+        let input = "\
+[1518-11-01 00:05] falls asleep
+[1518-11-01 00:06] wakes up
+[1518-11-01 23:58] Guard #10 begins shift
+[1518-11-02 00:10] falls asleep
+[1518-11-02 00:20] falls asleep
+[1518-11-02 00:30] wakes up
+[1518-11-02 00:40] wakes up
+[1518-11-02 00:50] falls asleep
+[1518-11-02 23:50] Guard #99 begins shift
+[1518-11-03 01:10] falls asleep
+[1518-02-29 00:00] Guard #7 begins shift
+[1518-11-04 00:75] wakes up
+";
+        assert_eq!(problems(input), vec![
+            (ProblemKind::InvalidDate, "line 11: invalid date: [1518-02-29 00:00] Guard #7 begins shift".to_string()),
+            (ProblemKind::SleepBeforeShift, "line 1: sleep before any shift: [1518-11-01 00:05] falls asleep".to_string()),
+            (ProblemKind::WakeWithoutSleep, "line 2: wakes up without sleeping: [1518-11-01 00:06] wakes up".to_string()),
+            (ProblemKind::DoubleSleep, "line 5: falls asleep while asleep: [1518-11-02 00:20] falls asleep".to_string()),
+            (ProblemKind::WakeWithoutSleep, "line 7: wakes up without sleeping: [1518-11-02 00:40] wakes up".to_string()),
+            (ProblemKind::ShiftChangeWhileAsleep, "line 9: shift change while asleep: [1518-11-02 23:50] Guard #99 begins shift".to_string()),
+            (ProblemKind::SleepOutsideMidnight, "line 10: sleep outside the midnight hour: [1518-11-03 01:10] falls asleep".to_string()),
+            (ProblemKind::InvalidTime, "line 12: invalid time: [1518-11-04 00:75] wakes up".to_string()),
+        ]);
+    }
+
+    #[test]
+    fn first_problem_as_error() {
+        let input = "[1518-11-01 00:00] Guard #10 begins shift\n[1518-11-01 00:06] wakes up\n";
+        assert_eq!(read_shifts(input, &mut Diagnostics::new()),
+            Err(ParseError::new(2, 20, "\"falls asleep\"", "\"wakes up\"")));
+        let input = "[1518-11-31 00:00] Guard #10 begins shift\n";
+        assert_eq!(read_shifts(input, &mut Diagnostics::new()),
+            Err(ParseError::new(1, 2, "valid calendar date", "\"1518-11-31\"")));
+    }
+}
@@ -0,0 +1,4 @@
+pub mod log;
+pub mod most_frequent_minute;
+pub mod sleepiest_guard;
+pub mod stats;
@@ -0,0 +1,54 @@
+use crate::diagnostics::Diagnostics;
+use crate::parse::ParseError;
+
+use super::log;
+use super::stats;
+
+/// Strategy 2: the guard most frequently asleep on the same minute, times
+/// that minute.
+pub fn solve(input: &str) -> Result<Option<usize>, ParseError> {
+    return solve_with(input, &mut Diagnostics::new());
+}
+
+/// Like `solve`, also tracing the guard table and the shift timeline.
+pub fn solve_with(input: &str, diagnostics: &mut Diagnostics) -> Result<Option<usize>, ParseError> {
+    let shifts = log::read_shifts(input, diagnostics)?;
+    let stats = stats::guard_stats(&shifts);
+    diagnostics.trace(|| stats::format_table(&stats).trim_end().to_string());
+    diagnostics.trace(|| stats::format_timeline(&shifts).trim_end().to_string());
+
+    let mut best: Option<(u32, usize, usize)> = None;
+    for s in &stats {
+        match s.peak_minute() {
+            Some((minute, count)) => {
+                let better = match best {
+                    Some((best_id, _, best_count)) => count > best_count || (count == best_count && s.guard_id < best_id),
+                    None => true,
+                };
+                if better {
+                    best = Some((s.guard_id, minute, count));
+                }
+            },
+            None => {},
+        }
+    }
+
+    match best {
+        Some((guard_id, minute, _)) => {
+            diagnostics.set("guard_id", guard_id);
+            diagnostics.set("minute", minute);
+            return Ok(Some(guard_id as usize * minute));
+        },
+        None => return Ok(None),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example() {
+        assert_eq!(solve(log::EXAMPLE), Ok(Some(4455)));
+    }
+}
@@ -0,0 +1,48 @@
+use crate::diagnostics::Diagnostics;
+use crate::parse::ParseError;
+
+use super::log;
+use super::stats;
+
+/// Strategy 1: the guard with the most minutes asleep, times the minute
+/// they were asleep on most often.
+pub fn solve(input: &str) -> Result<Option<usize>, ParseError> {
+    return solve_with(input, &mut Diagnostics::new());
+}
+
+/// Like `solve`, also tracing the guard table and the shift timeline.
+pub fn solve_with(input: &str, diagnostics: &mut Diagnostics) -> Result<Option<usize>, ParseError> {
+    let shifts = log::read_shifts(input, diagnostics)?;
+    let stats = stats::guard_stats(&shifts);
+    diagnostics.trace(|| stats::format_table(&stats).trim_end().to_string());
+    diagnostics.trace(|| stats::format_timeline(&shifts).trim_end().to_string());
+
+    let sleepiest = match stats.first() {
+        Some(s) => s,
+        None => return Ok(None),
+    };
+    let (minute, _) = match sleepiest.peak_minute() {
+        Some(peak) => peak,
+        None => return Ok(None),
+    };
+    diagnostics.set("guard_id", sleepiest.guard_id);
+    diagnostics.set("minute", minute);
+    return Ok(Some(sleepiest.guard_id as usize * minute));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example() {
+        assert_eq!(solve(log::EXAMPLE), Ok(Some(240)));
+    }
+
+    #[test]
+    fn example_in_any_order() {
+        let mut lines: Vec<&str> = log::EXAMPLE.lines().collect();
+        lines.reverse();
+        assert_eq!(solve(&lines.join("\n")), Ok(Some(240)));
+    }
+}
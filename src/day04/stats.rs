@@ -0,0 +1,118 @@
+use std::collections::BTreeMap;
+use std::fmt::Write;
+
+use super::log::Shift;
+
+/// Sleep totals of one guard over all of their shifts.
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub struct GuardStats {
+    pub guard_id: u32,
+    pub num_of_shifts: usize,
+    pub total_minutes: usize,
+    /// Number of shifts the guard was asleep on each minute.
+    pub minutes: [usize; 60],
+}
+impl GuardStats {
+    /// Earliest minute the guard was asleep on most often, with the number
+    /// of times, or `None` if the guard never slept.
+    pub fn peak_minute(&self) -> Option<(usize, usize)> {
+        let mut peak: Option<(usize, usize)> = None;
+        for (minute, count) in self.minutes.iter().enumerate() {
+            if *count > 0 && peak.is_none_or(|(_, peak_count)| *count > peak_count) {
+                peak = Some((minute, *count));
+            }
+        }
+        return peak;
+    }
+}
+
+/// Stats of every guard, ranked by total minutes asleep (then by ID).
+pub fn guard_stats(shifts: &[Shift]) -> Vec<GuardStats> {
+    let mut stats_map: BTreeMap<u32, GuardStats> = BTreeMap::new();
+    for shift in shifts {
+        let stats = stats_map.entry(shift.guard_id).or_insert(GuardStats {
+            guard_id: shift.guard_id,
+            num_of_shifts: 0,
+            total_minutes: 0,
+            minutes: [0; 60],
+        });
+        stats.num_of_shifts += 1;
+        for (minute, asleep) in shift.asleep.iter().enumerate() {
+            if *asleep {
+                stats.total_minutes += 1;
+                stats.minutes[minute] += 1;
+            }
+        }
+    }
+    let mut stats: Vec<GuardStats> = stats_map.into_values().collect();
+    stats.sort_by(|a, b| b.total_minutes.cmp(&a.total_minutes).then(a.guard_id.cmp(&b.guard_id)));
+    return stats;
+}
+
+pub fn format_table(stats: &[GuardStats]) -> String {
+    let mut table = String::from("Rank  Guard  Shifts  Minutes  Peak minute\n");
+    for (rank, s) in stats.iter().enumerate() {
+        let peak = match s.peak_minute() {
+            Some((minute, count)) => format!("{:02} ({}x)", minute, count),
+            None => "-".to_string(),
+        };
+        writeln!(table, "{:>4}  {:>5}  {:>6}  {:>7}  {}",
+            rank + 1, format!("#{}", s.guard_id), s.num_of_shifts, s.total_minutes, peak).unwrap();
+    }
+    return table;
+}
+
+/// Shifts in the layout of the puzzle statement, one row per shift with
+/// `#` for every minute asleep.
+pub fn format_timeline(shifts: &[Shift]) -> String {
+    let id_width = shifts.iter().map(|s| s.guard_id.to_string().len()).max().unwrap_or(2);
+    let margin = " ".repeat(10 + id_width);
+    let mut timeline = String::new();
+    writeln!(timeline, "{:<7}{:<width$}Minute", "Date", "ID", width = id_width + 3).unwrap();
+    let tens: String = (0..60).map(|m| std::char::from_digit(m / 10, 10).unwrap()).collect();
+    let ones: String = (0..60).map(|m| std::char::from_digit(m % 10, 10).unwrap()).collect();
+    writeln!(timeline, "{}{}", margin, tens).unwrap();
+    writeln!(timeline, "{}{}", margin, ones).unwrap();
+    for shift in shifts {
+        let minutes: String = shift.asleep.iter().map(|a| if *a { '#' } else { '.' }).collect();
+        writeln!(timeline, "{:02}-{:02}  #{:<width$}  {}",
+            shift.date.m, shift.date.d, shift.guard_id, minutes, width = id_width).unwrap();
+    }
+    return timeline;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use super::super::log;
+
+    #[test]
+    fn example_timeline() {
+        let shifts = log::shifts(&log::parse_events(log::EXAMPLE).unwrap());
+        let expected = "\
+Date   ID   Minute
+            000000000011111111112222222222333333333344444444445555555555
+            012345678901234567890123456789012345678901234567890123456789
+11-01  #10  .....####################.....#########################.....
+11-02  #99  ........................................##########..........
+11-03  #10  ........................#####...............................
+11-04  #99  ....................................##########..............
+11-05  #99  .............................................##########.....
+";
+        assert_eq!(format_timeline(&shifts), expected);
+    }
+
+    #[test]
+    fn example_table() {
+        let shifts = log::shifts(&log::parse_events(log::EXAMPLE).unwrap());
+        let stats = guard_stats(&shifts);
+        assert_eq!(stats[0].peak_minute(), Some((24, 2)));
+        assert_eq!(stats[1].peak_minute(), Some((45, 3)));
+        let expected = "\
+Rank  Guard  Shifts  Minutes  Peak minute
+   1    #10       2       50  24 (2x)
+   2    #99       3       30  45 (3x)
+";
+        assert_eq!(format_table(&stats), expected);
+    }
+}
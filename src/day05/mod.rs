@@ -0,0 +1,3 @@
+pub mod most_blocking_polymer;
+pub mod polymer_reaction;
+pub mod reactor;
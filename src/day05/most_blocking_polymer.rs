@@ -0,0 +1,39 @@
+use crate::diagnostics::Diagnostics;
+
+use super::reactor;
+use super::reactor::Reactor;
+
+pub fn solve(input: &str) -> usize {
+    return solve_with(input, 1, &mut Diagnostics::new());
+}
+
+/// Like `solve`, trying the unit types in `num_of_threads` threads and
+/// reporting the length after removing each of them.
+pub fn solve_with(input: &str, num_of_threads: usize, diagnostics: &mut Diagnostics) -> usize {
+    let polymer = input.trim().as_bytes();
+    let reactor = Reactor::new();
+    let reduced = reactor.react(polymer);
+    let lengths = reactor.removal_lengths(&reduced, &reactor::unit_types(polymer), num_of_threads);
+    for (unit_type, len) in &lengths {
+        diagnostics.set(&format!("without_{}", *unit_type as char), *len);
+    }
+    return lengths.iter().map(|(_, len)| *len).min().unwrap_or(reduced.len());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example() {
+        assert_eq!(solve("dabAcCaCBAcCcaDA\n"), 4);
+    }
+
+    #[test]
+    fn example_report() {
+        let mut diagnostics = Diagnostics::new();
+        assert_eq!(solve_with("dabAcCaCBAcCcaDA\n", 4, &mut diagnostics), 4);
+        assert_eq!(diagnostics.get("without_b"), Some(&8.into()));
+        assert_eq!(diagnostics.values().len(), 4);
+    }
+}
@@ -0,0 +1,44 @@
+use crate::diagnostics::Diagnostics;
+
+use super::reactor::Reactor;
+
+pub fn solve(input: &str) -> usize {
+    return solve_with(input, &mut Diagnostics::new());
+}
+
+/// Like `solve`, tracing every annihilation with the positions of both
+/// units in the input.
+pub fn solve_with(input: &str, diagnostics: &mut Diagnostics) -> usize {
+    let polymer = input.trim().as_bytes();
+    let reactor = Reactor::new();
+    if !diagnostics.is_tracing() {
+        return reactor.react(polymer).len();
+    }
+    let (remaining, annihilations) = reactor.react_traced(polymer);
+    for a in &annihilations {
+        diagnostics.trace(|| format!("{} at {} annihilates {} at {}",
+            a.left_unit as char, a.left, a.right_unit as char, a.right));
+    }
+    return remaining.len();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn examples() {
+        assert_eq!(solve("aA"), 0);
+        assert_eq!(solve("abBA"), 0);
+        assert_eq!(solve("abAB"), 4);
+        assert_eq!(solve("aabAAB"), 6);
+        assert_eq!(solve("dabAcCaCBAcCcaDA\n"), 10);
+    }
+
+    #[test]
+    fn example_trace() {
+        let mut diagnostics = Diagnostics::with_trace();
+        assert_eq!(solve_with("abBA\n", &mut diagnostics), 0);
+        assert_eq!(diagnostics.trace_lines(), ["b at 1 annihilates B at 2", "a at 0 annihilates A at 3"]);
+    }
+}
@@ -0,0 +1,169 @@
+use std::thread;
+
+/// Decides whether two adjacent units annihilate.
+pub trait ReactionRule {
+    fn reacts(&self, left: u8, right: u8) -> bool;
+}
+
+/// Puzzle rule: the same unit type in opposite polarities (letter case).
+#[derive(Clone, Copy, Debug, Default)]
+pub struct CasePair;
+impl ReactionRule for CasePair {
+    fn reacts(&self, left: u8, right: u8) -> bool {
+        return left != right && left.eq_ignore_ascii_case(&right);
+    }
+}
+
+impl<F: Fn(u8, u8) -> bool> ReactionRule for F {
+    fn reacts(&self, left: u8, right: u8) -> bool {
+        return self(left, right);
+    }
+}
+
+/// Two units which annihilated, with their indices in the original polymer.
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub struct Annihilation {
+    pub left: usize,
+    pub right: usize,
+    pub left_unit: u8,
+    pub right_unit: u8,
+}
+
+pub struct Reactor<R: ReactionRule = CasePair> {
+    rule: R,
+}
+impl Reactor<CasePair> {
+    pub fn new() -> Reactor<CasePair> {
+        Reactor { rule: CasePair }
+    }
+
+    /// Length of the fully reacted polymer after removing each of given unit
+    /// types, split between `num_of_threads` threads.
+    ///
+    /// Under the case-pair rule, removing a unit type and reacting gives the
+    /// same length whether or not the polymer has reacted beforehand, so
+    /// passing the reduced polymer is much faster. Other rules need not
+    /// behave like that, so this is not offered for them.
+    pub fn removal_lengths(&self, polymer: &[u8], unit_types: &[u8], num_of_threads: usize) -> Vec<(u8, usize)> {
+        let measure = |types: &[u8]| -> Vec<(u8, usize)> {
+            types.iter().map(|t| (*t, self.react_without(polymer, *t).len())).collect()
+        };
+        if num_of_threads <= 1 || unit_types.len() <= 1 {
+            return measure(unit_types);
+        }
+        let chunk_size = unit_types.len().div_ceil(num_of_threads);
+        return thread::scope(|scope| {
+            let handles: Vec<_> = unit_types.chunks(chunk_size)
+                .map(|types| scope.spawn(move || measure(types)))
+                .collect();
+            handles.into_iter().flat_map(|h| h.join().expect("reactor thread panicked")).collect()
+        });
+    }
+}
+impl Default for Reactor<CasePair> {
+    fn default() -> Self {
+        Reactor::new()
+    }
+}
+impl<R: ReactionRule> Reactor<R> {
+    pub fn with_rule(rule: R) -> Reactor<R> {
+        Reactor { rule }
+    }
+
+    /// Reacts the polymer fully, skipping the units accepted by `skip` and
+    /// reporting every annihilation to `on_annihilation`.
+    fn react_filtered(&self, polymer: &[u8], skip: impl Fn(u8) -> bool, mut on_annihilation: impl FnMut(Annihilation)) -> Vec<u8> {
+        let mut stack: Vec<(usize, u8)> = Vec::with_capacity(polymer.len());
+        for (index, &unit) in polymer.iter().enumerate() {
+            if skip(unit) {
+                continue;
+            }
+            match stack.last() {
+                Some(&(top_index, top)) if self.rule.reacts(top, unit) => {
+                    stack.pop();
+                    on_annihilation(Annihilation { left: top_index, right: index, left_unit: top, right_unit: unit });
+                },
+                _ => stack.push((index, unit)),
+            }
+        }
+        return stack.into_iter().map(|(_, unit)| unit).collect();
+    }
+
+    /// Units remaining after the polymer reacts fully.
+    pub fn react(&self, polymer: &[u8]) -> Vec<u8> {
+        return self.react_filtered(polymer, |_| false, |_| {});
+    }
+
+    /// Like `react`, also returning the annihilations in the order they
+    /// happen.
+    pub fn react_traced(&self, polymer: &[u8]) -> (Vec<u8>, Vec<Annihilation>) {
+        let mut annihilations = Vec::new();
+        let remaining = self.react_filtered(polymer, |_| false, |a| annihilations.push(a));
+        return (remaining, annihilations);
+    }
+
+    /// Units remaining after removing all units of given type (in either
+    /// polarity) and reacting the polymer fully.
+    pub fn react_without(&self, polymer: &[u8], unit_type: u8) -> Vec<u8> {
+        return self.react_filtered(polymer, |unit| unit.eq_ignore_ascii_case(&unit_type), |_| {});
+    }
+}
+
+/// Lowercase unit types occurring in the polymer, in alphabetical order.
+pub fn unit_types(polymer: &[u8]) -> Vec<u8> {
+    let mut present = [false; 256];
+    for unit in polymer {
+        present[unit.to_ascii_lowercase() as usize] = true;
+    }
+    return (b'a'..=b'z').filter(|t| present[*t as usize]).collect();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &[u8] = b"dabAcCaCBAcCcaDA";
+
+    #[test]
+    fn example_reactions() {
+        let reactor = Reactor::new();
+        assert_eq!(reactor.react(EXAMPLE), b"dabCBAcaDA");
+        assert_eq!(reactor.react_without(EXAMPLE, b'a'), b"dbCBcD");
+        assert_eq!(reactor.react_without(EXAMPLE, b'c'), b"daDA");
+    }
+
+    #[test]
+    fn example_removals() {
+        let reactor = Reactor::new();
+        let expected = vec![(b'a', 6), (b'b', 8), (b'c', 4), (b'd', 6)];
+        assert_eq!(unit_types(EXAMPLE), b"abcd");
+        assert_eq!(reactor.removal_lengths(EXAMPLE, b"abcd", 1), expected);
+        assert_eq!(reactor.removal_lengths(&reactor.react(EXAMPLE), b"abcd", 1), expected);
+        assert_eq!(reactor.removal_lengths(EXAMPLE, b"abcd", 3), expected);
+    }
+
+    #[test]
+    fn example_trace() {
+        let (remaining, annihilations) = Reactor::new().react_traced(EXAMPLE);
+        assert_eq!(remaining, b"dabCBAcaDA");
+        // cC at 4-5, then Aa at 3-6 (the statement's first two steps), then Cc at 10-11.
+        let pairs: Vec<(usize, usize)> = annihilations.iter().map(|a| (a.left, a.right)).collect();
+        assert_eq!(pairs, vec![(4, 5), (3, 6), (10, 11)]);
+        assert_eq!((annihilations[1].left_unit, annihilations[1].right_unit), (b'A', b'a'));
+    }
+
+    #[test]
+    fn custom_rule() {
+        let brackets = Reactor::with_rule(|left, right| match (left, right) {
+            (b'(', b')') | (b'[', b']') | (b'{', b'}') => true,
+            _ => false,
+        });
+        assert_eq!(brackets.react(b"([]{()})"), b"");
+        assert_eq!(brackets.react(b"([)]"), b"([)]");
+        assert_eq!(brackets.react(b"(]()"), b"(]");
+        // Reacting before a removal may change the result under other rules.
+        let chain = Reactor::with_rule(|left, right| (left, right) == (b'a', b'b') || (left, right) == (b'b', b'c'));
+        assert_eq!(chain.react_without(b"abc", b'a'), b"");
+        assert_eq!(chain.react_without(&chain.react(b"abc"), b'a'), b"c");
+    }
+}
@@ -0,0 +1,22 @@
+use crate::geometry::Point2;
+use crate::parse;
+use crate::parse::ParseError;
+
+/// Coordinates of the locations, one `x, y` pair per line.
+pub fn parse_coordinates(input: &str) -> Result<Vec<Point2>, ParseError> {
+    let mut points: Vec<Point2> = Vec::new();
+    for mut line in parse::lines(input) {
+        let x = line.integer()?;
+        line.literal(",")?;
+        let y = line.integer()?;
+        line.end()?;
+        points.push(Point2::new([x, y]));
+    }
+    if points.is_empty() {
+        return Err(ParseError::new(1, 1, "coordinates", "end of input"));
+    }
+    return Ok(points);
+}
+
+#[cfg(test)]
+pub(super) const EXAMPLE: &str = "1, 1\n1, 6\n8, 3\n3, 4\n5, 5\n8, 9\n";
@@ -0,0 +1,305 @@
+use std::fmt::Write;
+
+use crate::diagnostics::Diagnostics;
+use crate::geometry::BoundingBox;
+use crate::geometry::Chebyshev;
+use crate::geometry::Coord;
+use crate::geometry::Manhattan;
+use crate::geometry::Metric;
+use crate::geometry::MetricKind;
+use crate::geometry::SquaredEuclidean;
+use crate::geometry::Point2;
+use crate::grid::Grid;
+use crate::grid::Pos;
+use crate::parse::ParseError;
+
+use super::coordinates;
+
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+pub enum MapCell {
+    OneLocation(usize, Coord),  // location index, dist
+    MultipleLocations(Coord),
+}
+impl MapCell {
+    /// Distance to the nearest location(s).
+    pub fn dist(&self) -> Coord {
+        match *self {
+            MapCell::OneLocation(_, dist) => dist,
+            MapCell::MultipleLocations(dist) => dist,
+        }
+    }
+}
+
+/// Number of positions closest to a single location. For an infinite area
+/// `size` counts only the positions inside the bounding box.
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+pub struct Area {
+    pub location: Point2,
+    pub size: usize,
+    pub infinite: bool,
+}
+
+/// Metrics for which it is known which locations own infinitely many
+/// positions.
+pub trait AreaMetric: Metric {
+    /// Whether each of the locations owns infinitely many positions, given
+    /// the owners of the positions in their bounding box.
+    fn infinite_locations(&self, locations: &[Point2], cells: &Grid<MapCell>) -> Vec<bool>;
+}
+
+impl AreaMetric for Manhattan {
+    /// Moving out of the bounding box across its boundary adds the same
+    /// distance to every location, so the owners of the boundary positions
+    /// are the ones owning infinite areas.
+    fn infinite_locations(&self, locations: &[Point2], cells: &Grid<MapCell>) -> Vec<bool> {
+        let mut infinite = vec![false; locations.len()];
+        let (width, height) = (cells.width(), cells.height());
+        for (x, y) in cells.positions() {
+            if x == 0 || y == 0 || x == width - 1 || y == height - 1 {
+                match cells[(x, y)] {
+                    MapCell::OneLocation(index, _) => infinite[index] = true,
+                    MapCell::MultipleLocations(_) => {},
+                }
+            }
+        }
+        return infinite;
+    }
+}
+
+impl AreaMetric for Chebyshev {
+    /// Far out along a diagonal, a location is the nearest one unless another
+    /// location lies at least as far along both axes in that direction.
+    fn infinite_locations(&self, locations: &[Point2], _cells: &Grid<MapCell>) -> Vec<bool> {
+        let directions = [(1, 1), (1, -1), (-1, 1), (-1, -1)];
+        return locations.iter().enumerate().map(|(i, l)| {
+            directions.iter().any(|(sx, sy)| {
+                locations.iter().enumerate()
+                    .all(|(j, m)| j == i || sx * (m.x() - l.x()) < 0 || sy * (m.y() - l.y()) < 0)
+            })
+        }).collect();
+    }
+}
+
+impl AreaMetric for SquaredEuclidean {
+    /// The area of a location is unbounded exactly when the location lies on
+    /// the boundary of the convex hull of all of them (and is not repeated).
+    fn infinite_locations(&self, locations: &[Point2], _cells: &Grid<MapCell>) -> Vec<bool> {
+        return (0..locations.len()).map(|i| on_hull_boundary(locations, i)).collect();
+    }
+}
+
+impl AreaMetric for MetricKind {
+    fn infinite_locations(&self, locations: &[Point2], cells: &Grid<MapCell>) -> Vec<bool> {
+        match self {
+            MetricKind::Manhattan => Manhattan.infinite_locations(locations, cells),
+            MetricKind::Chebyshev => Chebyshev.infinite_locations(locations, cells),
+            MetricKind::SquaredEuclidean => SquaredEuclidean.infinite_locations(locations, cells),
+        }
+    }
+}
+
+/// Whether some closed half-plane bounded by a line through the location
+/// holds all the other locations, none of which coincides with it.
+fn on_hull_boundary(locations: &[Point2], index: usize) -> bool {
+    let l = locations[index];
+    let offsets: Vec<Point2> = locations.iter().enumerate()
+        .filter(|(j, _)| *j != index)
+        .map(|(_, m)| *m - l)
+        .collect();
+    if offsets.contains(&Point2::origin()) {
+        return false;
+    }
+    if offsets.is_empty() {
+        return true;
+    }
+    // Such a half-plane can be turned around the location until its boundary
+    // meets another location, so only normals of the offsets are tried.
+    return offsets.iter().any(|o| {
+        let normals = [Point2::new([-o.y(), o.x()]), Point2::new([o.y(), -o.x()])];
+        normals.iter().any(|n| offsets.iter().all(|w| w.x() * n.x() + w.y() * n.y() <= 0))
+    });
+}
+
+/// Nearest locations of every position in the bounding box of the
+/// locations, stored densely, together with the locations owning infinite
+/// areas.
+pub struct OwnershipMap {
+    pub locations: Vec<Point2>,
+    pub bounds: BoundingBox<2>,
+    pub cells: Grid<MapCell>,
+    pub infinite: Vec<bool>,
+}
+impl OwnershipMap {
+    /// Map of given locations under given metric, `None` if there are none.
+    pub fn new<M: AreaMetric>(locations: &[Point2], metric: &M) -> Option<OwnershipMap> {
+        let bounds = BoundingBox::from_points(locations)?;
+        let extent = bounds.extent();
+        let (width, height) = (extent.coords[0] as usize, extent.coords[1] as usize);
+        let mut cells = Vec::with_capacity(width * height);
+        for y in 0..height {
+            for x in 0..width {
+                let p = bounds.min + Point2::new([x as Coord, y as Coord]);
+                cells.push(nearest(locations, &p, metric));
+            }
+        }
+        let cells = Grid::from_vec(width, height, cells);
+        let infinite = metric.infinite_locations(locations, &cells);
+        return Some(OwnershipMap { locations: locations.to_vec(), bounds, cells, infinite });
+    }
+
+    /// Point at given position of the grid.
+    pub fn point(&self, pos: Pos) -> Point2 {
+        return self.bounds.min + Point2::new([pos.0 as Coord, pos.1 as Coord]);
+    }
+
+    /// Areas of all the locations, in input order.
+    pub fn areas(&self) -> Vec<Area> {
+        let mut areas: Vec<Area> = self.locations.iter().zip(&self.infinite)
+            .map(|(l, infinite)| Area { location: *l, size: 0, infinite: *infinite })
+            .collect();
+        for cell in self.cells.iter() {
+            match *cell {
+                MapCell::OneLocation(index, _) => areas[index].size += 1,
+                MapCell::MultipleLocations(_) => {},
+            }
+        }
+        return areas;
+    }
+}
+
+fn nearest<M: Metric>(locations: &[Point2], p: &Point2, metric: &M) -> MapCell {
+    let mut cell = MapCell::MultipleLocations(Coord::MAX);
+    for (index, l) in locations.iter().enumerate() {
+        let dist = metric.dist(l, p);
+        if dist < cell.dist() {
+            cell = MapCell::OneLocation(index, dist);
+        } else if dist == cell.dist() {
+            cell = MapCell::MultipleLocations(dist);
+        }
+    }
+    return cell;
+}
+
+pub fn format_table(areas: &[Area]) -> String {
+    let mut table = String::from("Location  Position  Area\n");
+    for (index, a) in areas.iter().enumerate() {
+        let size = if a.infinite { "infinite".to_string() } else { a.size.to_string() };
+        let position = format!("{},{}", a.location.coords[0], a.location.coords[1]);
+        writeln!(table, "{:>8}  {:<8}  {}", index, position, size).unwrap();
+    }
+    return table;
+}
+
+pub fn solve(input: &str) -> Result<usize, ParseError> {
+    return solve_with(input, &Manhattan, &mut Diagnostics::new());
+}
+
+/// Like `solve` for given metric, also tracing the area table.
+pub fn solve_with<M: AreaMetric>(input: &str, metric: &M, diagnostics: &mut Diagnostics) -> Result<usize, ParseError> {
+    let locations = coordinates::parse_coordinates(input)?;
+    let areas = OwnershipMap::new(&locations, metric).unwrap().areas();
+    diagnostics.trace(|| format_table(&areas).trim_end().to_string());
+    return Ok(areas.iter().filter(|a| !a.infinite).map(|a| a.size).max().unwrap_or(0));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example() {
+        assert_eq!(solve(coordinates::EXAMPLE), Ok(17));
+    }
+
+    #[test]
+    fn example_areas() {
+        let locations = coordinates::parse_coordinates(coordinates::EXAMPLE).unwrap();
+        let map = OwnershipMap::new(&locations, &Manhattan).unwrap();
+        assert_eq!(map.cells[(0, 0)], MapCell::OneLocation(0, 0));
+        // Position 5,1 from the statement is equally far from A and E.
+        assert_eq!(map.point((4, 0)), Point2::new([5, 1]));
+        assert_eq!(map.cells[(4, 0)], MapCell::MultipleLocations(4));
+        let areas = map.areas();
+        let infinite: Vec<bool> = areas.iter().map(|a| a.infinite).collect();
+        assert_eq!(infinite, [true, true, true, false, false, true]);
+        assert_eq!((areas[3].size, areas[4].size), (9, 17));
+    }
+
+    #[test]
+    fn example_table() {
+        let mut diagnostics = Diagnostics::with_trace();
+        assert_eq!(solve_with(coordinates::EXAMPLE, &Manhattan, &mut diagnostics), Ok(17));
+        let expected = "\
+Location  Position  Area
+       0  1,1       infinite
+       1  1,6       infinite
+       2  8,3       infinite
+       3  3,4       9
+       4  5,5       17
+       5  8,9       infinite";
+        assert_eq!(diagnostics.trace_lines(), [expected]);
+    }
+
+    #[test]
+    fn other_metrics() {
+        let locations = coordinates::parse_coordinates(coordinates::EXAMPLE).unwrap();
+        let map = OwnershipMap::new(&locations, &Chebyshev).unwrap();
+        // 2,2 is one step from A diagonally but two steps from D.
+        assert_eq!(map.cells[(1, 1)], MapCell::OneLocation(0, 1));
+        let map = OwnershipMap::new(&locations, &SquaredEuclidean).unwrap();
+        assert_eq!(map.cells[(3, 2)], MapCell::OneLocation(3, 2));
+        let enclosed: Vec<usize> = map.areas().iter().enumerate()
+            .filter(|(_, a)| !a.infinite)
+            .map(|(i, _)| i)
+            .collect();
+        assert_eq!(enclosed, [3, 4]);
+    }
+
+    #[test]
+    fn enclosed_location_touching_boundary() {
+        // 5,1 owns 5,0 on the boundary of the box, yet for these metrics the
+        // other locations enclose its area.
+        let input = "0, 0\n10, 0\n5, 10\n5, 1\n";
+        let locations = coordinates::parse_coordinates(input).unwrap();
+        let map = OwnershipMap::new(&locations, &SquaredEuclidean).unwrap();
+        assert_eq!(map.cells[(5, 0)], MapCell::OneLocation(3, 1));
+        assert_eq!(map.infinite, [true, true, true, false]);
+        assert_eq!(OwnershipMap::new(&locations, &Chebyshev).unwrap().infinite, [true, true, true, false]);
+        assert!(solve_with(input, &SquaredEuclidean, &mut Diagnostics::new()).unwrap() > 0);
+        assert!(solve_with(input, &Chebyshev, &mut Diagnostics::new()).unwrap() > 0);
+        assert_eq!(OwnershipMap::new(&locations, &Manhattan).unwrap().infinite, [true, true, true, true]);
+    }
+
+    #[test]
+    fn infinite_areas_reach_far() {
+        // Locations with infinite areas, and only those, own positions on a
+        // square far outside their bounding box.
+        let inputs = [
+            coordinates::EXAMPLE,
+            "0, 0\n10, 0\n5, 10\n5, 1\n",
+            "0, 0\n4, 0\n8, 0\n4, 3\n4, 9\n2, 6\n",
+            "3, 3\n3, 3\n7, 2\n",
+        ];
+        for input in inputs {
+            let locations = coordinates::parse_coordinates(input).unwrap();
+            assert_eq!(far_owners(&locations, &Manhattan), OwnershipMap::new(&locations, &Manhattan).unwrap().infinite);
+            assert_eq!(far_owners(&locations, &Chebyshev), OwnershipMap::new(&locations, &Chebyshev).unwrap().infinite);
+            assert_eq!(far_owners(&locations, &SquaredEuclidean),
+                OwnershipMap::new(&locations, &SquaredEuclidean).unwrap().infinite);
+        }
+    }
+
+    fn far_owners<M: Metric>(locations: &[Point2], metric: &M) -> Vec<bool> {
+        let r = 1000;
+        let mut owners = vec![false; locations.len()];
+        for t in -r..=r {
+            for p in [[t, -r], [t, r], [-r, t], [r, t]] {
+                match nearest(locations, &Point2::new(p), metric) {
+                    MapCell::OneLocation(index, _) => owners[index] = true,
+                    MapCell::MultipleLocations(_) => {},
+                }
+            }
+        }
+        return owners;
+    }
+}
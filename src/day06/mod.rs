@@ -0,0 +1,4 @@
+pub mod coordinates;
+pub mod largest_area;
+pub mod render;
+pub mod safe_region;
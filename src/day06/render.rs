@@ -0,0 +1,121 @@
+use crate::geometry::Coord;
+
+use super::largest_area::MapCell;
+use super::largest_area::OwnershipMap;
+
+const TIE_COLOUR: image::Rgb<u8> = image::Rgb([128, 128, 128]);
+const LOCATION_COLOUR: image::Rgb<u8> = image::Rgb([0, 0, 0]);
+
+/// Colour of the area of given location. Hues advance by the golden ratio,
+/// so that locations with close indices get clearly different colours.
+fn location_colour(index: usize) -> image::Rgb<u8> {
+    let hue = (index as f64 * 0.618_033_988_75).fract() * 6.0;
+    let (saturation, value) = (0.6, 0.85);
+    let c = value * saturation;
+    let x = c * (1.0 - (hue % 2.0 - 1.0).abs());
+    let (r, g, b) = match hue as u32 {
+        0 => (c, x, 0.0),
+        1 => (x, c, 0.0),
+        2 => (0.0, c, x),
+        3 => (0.0, x, c),
+        4 => (x, 0.0, c),
+        _ => (c, 0.0, x),
+    };
+    let m = value - c;
+    let channel = |v: f64| ((v + m) * 255.0).round() as u8;
+    return image::Rgb([channel(r), channel(g), channel(b)]);
+}
+
+/// Colour halfway towards white, marking the safe region.
+fn lighten(colour: image::Rgb<u8>) -> image::Rgb<u8> {
+    let image::Rgb([r, g, b]) = colour;
+    let channel = |v: u8| ((v as u16 + 255) / 2) as u8;
+    return image::Rgb([channel(r), channel(g), channel(b)]);
+}
+
+/// Image of the ownership map, one pixel per position: every location's
+/// area has its own colour and ties are grey. The locations are marked in
+/// black, together with their four neighbours. With `safe_limit`, positions
+/// of the part 2 safe region are lightened; like in part 2, their total
+/// distance is Manhattan whatever metric the map uses. The image covers only
+/// the bounding box, so any part of the region beyond it is clipped.
+pub fn ownership_image(map: &OwnershipMap, safe_limit: Option<Coord>) -> image::RgbImage {
+    let (width, height) = (map.cells.width(), map.cells.height());
+    let mut img = image::RgbImage::new(width as u32, height as u32);
+    for y in 0..height {
+        for x in 0..width {
+            let mut colour = match map.cells[(x, y)] {
+                MapCell::OneLocation(index, _) => location_colour(index),
+                MapCell::MultipleLocations(_) => TIE_COLOUR,
+            };
+            match safe_limit {
+                Some(limit) => {
+                    let p = map.point((x, y));
+                    let total: Coord = map.locations.iter().map(|l| l.manhattan(&p)).sum();
+                    if total < limit {
+                        colour = lighten(colour);
+                    }
+                },
+                None => {},
+            }
+            img.put_pixel(x as u32, y as u32, colour);
+        }
+    }
+    for l in &map.locations {
+        let centre = *l - map.bounds.min;
+        for (dx, dy) in [(0, 0), (-1, 0), (1, 0), (0, -1), (0, 1)] {
+            let (x, y) = (centre.coords[0] + dx, centre.coords[1] + dy);
+            if 0 <= x && x < width as Coord && 0 <= y && y < height as Coord {
+                img.put_pixel(x as u32, y as u32, LOCATION_COLOUR);
+            }
+        }
+    }
+    return img;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::geometry::Chebyshev;
+    use crate::geometry::Manhattan;
+    use super::super::coordinates;
+
+    #[test]
+    fn example_image() {
+        let locations = coordinates::parse_coordinates(coordinates::EXAMPLE).unwrap();
+        let map = OwnershipMap::new(&locations, &Manhattan).unwrap();
+        let img = ownership_image(&map, Some(32));
+        assert_eq!(img.dimensions(), (8, 9));
+        // Location A at 1,1 and its neighbour 2,1.
+        assert_eq!(*img.get_pixel(0, 0), LOCATION_COLOUR);
+        assert_eq!(*img.get_pixel(1, 0), LOCATION_COLOUR);
+        // 5,1 is a tie outside the safe region.
+        assert_eq!(*img.get_pixel(4, 0), TIE_COLOUR);
+        // 4,3 belongs to D and lies in the safe region.
+        assert_eq!(*img.get_pixel(3, 2), lighten(location_colour(3)));
+        // 8,7 belongs to F and lies outside of it.
+        assert_eq!(*img.get_pixel(7, 6), location_colour(5));
+    }
+
+    #[test]
+    fn safe_region_is_manhattan() {
+        let locations = coordinates::parse_coordinates(coordinates::EXAMPLE).unwrap();
+        let map = OwnershipMap::new(&locations, &Chebyshev).unwrap();
+        let img = ownership_image(&map, Some(32));
+        // 5,1 is a Chebyshev tie with total Chebyshev distance 27, but its
+        // total Manhattan distance of 38 puts it outside the safe region.
+        assert_eq!(map.cells[(4, 0)], MapCell::MultipleLocations(3));
+        assert_eq!(*img.get_pixel(4, 0), TIE_COLOUR);
+    }
+
+    #[test]
+    fn distinct_colours() {
+        let colours: Vec<_> = (0..6).map(location_colour).collect();
+        for i in 0..colours.len() {
+            assert!(colours[i] != TIE_COLOUR);
+            for j in 0..i {
+                assert!(colours[i] != colours[j]);
+            }
+        }
+    }
+}
@@ -0,0 +1,122 @@
+use crate::geometry::Coord;
+use crate::geometry::Point2;
+use crate::parse::ParseError;
+
+use super::coordinates;
+
+/// Puzzle bound on the total distance to all locations.
+pub const DEFAULT_LIMIT: Coord = 10000;
+
+/// Total distances along one axis to all given coordinates, for every
+/// position on the axis where the total is below `limit`.
+///
+/// The total is convex in the position and smallest at the median, so the
+/// positions are searched outwards from it until the total reaches the
+/// limit; every total takes a binary search over the prefix sums.
+fn axis_distance_sums(coords: &[Coord], limit: Coord) -> Vec<Coord> {
+    let mut sorted = coords.to_vec();
+    sorted.sort();
+    if sorted.is_empty() {
+        return Vec::new();
+    }
+    let n = sorted.len() as Coord;
+    let mut prefix_sums = vec![0];
+    for c in &sorted {
+        prefix_sums.push(prefix_sums[prefix_sums.len() - 1] + c);
+    }
+    let total = prefix_sums[sorted.len()];
+    let sum_at = |pos: Coord| -> Coord {
+        let num_below = sorted.partition_point(|c| *c <= pos);
+        let (k, sum_below) = (num_below as Coord, prefix_sums[num_below]);
+        return (k * pos - sum_below) + (total - sum_below - (n - k) * pos);
+    };
+    let median = sorted[(sorted.len() - 1) / 2];
+    let mut sums = Vec::new();
+    for step in [-1, 1] {
+        let mut pos = if step < 0 { median } else { median + 1 };
+        loop {
+            let sum = sum_at(pos);
+            if sum >= limit {
+                break;
+            }
+            sums.push(sum);
+            pos += step;
+        }
+    }
+    return sums;
+}
+
+/// Number of positions with total Manhattan distance to all the points
+/// below `limit`.
+///
+/// The total splits into independent sums along the two axes, so the
+/// region is counted by pairing the per-axis sums instead of visiting it.
+pub fn region_size(points: &[Point2], limit: Coord) -> usize {
+    let xs: Vec<Coord> = points.iter().map(|p| p.coords[0]).collect();
+    let ys: Vec<Coord> = points.iter().map(|p| p.coords[1]).collect();
+    let mut x_sums = axis_distance_sums(&xs, limit);
+    let mut y_sums = axis_distance_sums(&ys, limit);
+    x_sums.sort();
+    y_sums.sort();
+    let mut size = 0;
+    let mut num_y = y_sums.len();
+    for x_sum in &x_sums {
+        while num_y > 0 && x_sum + y_sums[num_y - 1] >= limit {
+            num_y -= 1;
+        }
+        size += num_y;
+    }
+    return size;
+}
+
+pub fn solve(input: &str) -> Result<usize, ParseError> {
+    return solve_with(input, DEFAULT_LIMIT);
+}
+
+/// Like `solve`, for given bound on the total distance.
+pub fn solve_with(input: &str, limit: Coord) -> Result<usize, ParseError> {
+    let points = coordinates::parse_coordinates(input)?;
+    return Ok(region_size(&points, limit));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example() {
+        assert_eq!(solve_with(coordinates::EXAMPLE, 32), Ok(16));
+    }
+
+    #[test]
+    fn region_outside_bounding_box() {
+        // A single point: the region is a diamond with radius limit - 1.
+        let single = [Point2::new([5, 5])];
+        assert_eq!(region_size(&single, 1), 1);
+        assert_eq!(region_size(&single, 3), 13);
+        assert_eq!(region_size(&single, 0), 0);
+        let points = [Point2::new([0, 0]), Point2::new([2, 1]), Point2::new([-1, 3])];
+        for limit in 0..20 {
+            let mut expected = 0;
+            for x in -limit..=limit {
+                for y in -limit..=limit {
+                    let total: Coord = points.iter().map(|p| p.manhattan(&Point2::new([x, y]))).sum();
+                    if total < limit {
+                        expected += 1;
+                    }
+                }
+            }
+            assert_eq!(region_size(&points, limit), expected, "limit {}", limit);
+        }
+    }
+
+    #[test]
+    fn far_apart_points() {
+        // Visiting the span between the points would take ages.
+        let far = 1_000_000_000_000;
+        let points = [Point2::new([0, 0]), Point2::new([far, -far])];
+        assert_eq!(region_size(&points, DEFAULT_LIMIT), 0);
+        let cluster = [Point2::new([far, far]), Point2::new([far + 2, far]), Point2::new([far + 1, far + 1])];
+        assert_eq!(region_size(&cluster, 4), 1);
+    }
+}
@@ -0,0 +1,233 @@
+use std::cmp;
+use std::collections::BTreeSet;
+use std::collections::HashMap;
+use std::fmt::Write;
+
+use crate::diagnostics::Diagnostics;
+use crate::parse::ParseError;
+
+use super::graph;
+use super::graph::GraphNode;
+
+/// Number of workers in the puzzle (including you).
+pub const DEFAULT_WORKERS: usize = 5;
+/// Time every step takes in the puzzle on top of its letter index.
+pub const DEFAULT_BASE_DURATION: u32 = 60;
+
+/// Time to finish given step: the base duration plus 1 for A, 2 for B etc.
+pub fn step_duration(id: char, base_duration: u32) -> u32 {
+    return base_duration + (id as u32 - 'A' as u32 + 1);
+}
+
+/// Step done by a worker from second `start` until (exclusive) `end`.
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub struct Task {
+    pub step: char,
+    pub worker: usize,
+    pub start: u32,
+    pub end: u32,
+}
+
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub struct Schedule {
+    pub num_of_workers: usize,
+    /// Tasks in order of their start (then of the worker).
+    pub tasks: Vec<Task>,
+    pub total_time: u32,
+}
+
+/// Simulates the workers taking the available steps in alphabetical order
+/// whenever they are idle.
+pub fn schedule(graph: &HashMap<char, GraphNode>, num_of_workers: usize, base_duration: u32) -> Schedule {
+    let mut num_edges_from: HashMap<char, usize> = graph.values().map(|n| (n.id, n.num_edges_from)).collect();
+    let mut available: BTreeSet<char> = graph.values().filter(|n| n.num_edges_from == 0).map(|n| n.id).collect();
+    let mut workers: Vec<Option<Task>> = vec![None; num_of_workers];
+    let mut tasks = Vec::new();
+    let mut time = 0;
+
+    loop {
+        for worker in workers.iter_mut() {
+            match *worker {
+                Some(task) if task.end == time => {
+                    *worker = None;
+                    for next_id in &graph[&task.step].edges_to {
+                        let count = num_edges_from.get_mut(next_id).unwrap();
+                        *count -= 1;
+                        if *count == 0 {
+                            available.insert(*next_id);
+                        }
+                    }
+                },
+                _ => {},
+            }
+        }
+        for (i, worker) in workers.iter_mut().enumerate() {
+            if worker.is_some() {
+                continue;
+            }
+            let step = match available.iter().next().cloned() {
+                Some(step) => step,
+                None => break,
+            };
+            available.remove(&step);
+            let task = Task { step, worker: i, start: time, end: time + step_duration(step, base_duration) };
+            tasks.push(task);
+            *worker = Some(task);
+        }
+        match workers.iter().flatten().map(|t| t.end).min() {
+            Some(end) => time = end,
+            None => break,
+        }
+    }
+    return Schedule { num_of_workers, tasks, total_time: time };
+}
+
+/// Total duration of the longest chain of steps starting at given step.
+fn tail_duration(graph: &HashMap<char, GraphNode>, id: char, base_duration: u32, tails: &mut HashMap<char, u32>) -> u32 {
+    match tails.get(&id) {
+        Some(tail) => return *tail,
+        None => {},
+    }
+    let mut longest_next = 0;
+    for next_id in &graph[&id].edges_to {
+        longest_next = cmp::max(longest_next, tail_duration(graph, *next_id, base_duration, tails));
+    }
+    let tail = step_duration(id, base_duration) + longest_next;
+    tails.insert(id, tail);
+    return tail;
+}
+
+/// Alphabetically first of given steps starting the longest chain.
+fn first_longest<'a>(ids: impl IntoIterator<Item = &'a char>, tails: &HashMap<char, u32>) -> Option<char> {
+    let mut longest: Option<char> = None;
+    for id in ids {
+        if longest.is_none_or(|l| tails[id] > tails[&l]) {
+            longest = Some(*id);
+        }
+    }
+    return longest;
+}
+
+/// Chain of dependent steps with the largest total duration; no number of
+/// workers can finish all the steps sooner.
+pub fn critical_path(graph: &HashMap<char, GraphNode>, base_duration: u32) -> Vec<char> {
+    let mut ids: Vec<char> = graph.keys().cloned().collect();
+    ids.sort();
+    let mut tails = HashMap::new();
+    for id in &ids {
+        tail_duration(graph, *id, base_duration, &mut tails);
+    }
+    let mut path = Vec::new();
+    let mut current = first_longest(&ids, &tails);
+    while let Some(id) = current {
+        path.push(id);
+        current = first_longest(&graph[&id].edges_to, &tails);
+    }
+    return path;
+}
+
+/// Schedule in the layout of the puzzle statement, one row per second.
+pub fn format_table(schedule: &Schedule) -> String {
+    let mut table = String::from("Second");
+    for i in 0..schedule.num_of_workers {
+        write!(table, "   Worker {}", i + 1).unwrap();
+    }
+    table.push_str("   Done\n");
+
+    let mut finished: Vec<&Task> = schedule.tasks.iter().collect();
+    finished.sort_by_key(|t| (t.end, t.step));
+    let mut num_done = 0;
+    let mut row = String::new();
+    for second in 0..=schedule.total_time {
+        while num_done < finished.len() && finished[num_done].end <= second {
+            num_done += 1;
+        }
+        row.clear();
+        write!(row, "{:^6}", second).unwrap();
+        for worker in 0..schedule.num_of_workers {
+            let step = schedule.tasks.iter()
+                .find(|t| t.worker == worker && t.start <= second && second < t.end)
+                .map_or('.', |t| t.step);
+            write!(row, "   {:^8}", step).unwrap();
+        }
+        let done: String = finished[..num_done].iter().map(|t| t.step).collect();
+        write!(row, "   {}", done).unwrap();
+        writeln!(table, "{}", row.trim_end()).unwrap();
+    }
+    return table;
+}
+
+pub fn solve(input: &str) -> Result<u32, ParseError> {
+    return solve_with(input, DEFAULT_WORKERS, DEFAULT_BASE_DURATION, &mut Diagnostics::new());
+}
+
+/// Like `solve` for given number of workers and base step duration, also
+/// reporting the critical path and tracing the schedule table.
+pub fn solve_with(input: &str, num_of_workers: usize, base_duration: u32, diagnostics: &mut Diagnostics) -> Result<u32, ParseError> {
+    let graph = graph::parse_graph(input)?;
+    let schedule = schedule(&graph, num_of_workers, base_duration);
+    diagnostics.set("critical_path", critical_path(&graph, base_duration).iter().collect::<String>());
+    diagnostics.trace(|| format_table(&schedule).trim_end().to_string());
+    return Ok(schedule.total_time);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example() {
+        let mut diagnostics = Diagnostics::with_trace();
+        assert_eq!(solve_with(graph::EXAMPLE, 2, 0, &mut diagnostics), Ok(15));
+        let expected = "\
+Second   Worker 1   Worker 2   Done
+  0         C          .
+  1         C          .
+  2         C          .
+  3         A          F       C
+  4         B          F       CA
+  5         B          F       CA
+  6         D          F       CAB
+  7         D          F       CAB
+  8         D          F       CAB
+  9         D          .       CABF
+  10        E          .       CABFD
+  11        E          .       CABFD
+  12        E          .       CABFD
+  13        E          .       CABFD
+  14        E          .       CABFD
+  15        .          .       CABFDE";
+        assert_eq!(diagnostics.trace_lines(), [expected]);
+    }
+
+    #[test]
+    fn durations() {
+        assert_eq!(step_duration('A', 60), 61);
+        assert_eq!(step_duration('Z', 60), 86);
+        assert_eq!(solve_with(graph::EXAMPLE, 1, 0, &mut Diagnostics::new()), Ok(21));
+        assert_eq!(solve(graph::EXAMPLE), Ok(253));
+    }
+
+    #[test]
+    fn example_critical_path() {
+        let graph = graph::parse_graph(graph::EXAMPLE).unwrap();
+        // C, F and E take 14 seconds, one more than C, A, D and E; with the
+        // base duration the longer chain wins.
+        assert_eq!(critical_path(&graph, 0), ['C', 'F', 'E']);
+        assert_eq!(critical_path(&graph, 60), ['C', 'A', 'D', 'E']);
+        // Unlimited workers finish exactly along the critical path.
+        assert_eq!(schedule(&graph, 10, 0).total_time, 14);
+    }
+
+    #[test]
+    fn repeated_dependency() {
+        let input = "\
+Step C must be finished before step A can begin.
+Step C must be finished before step A can begin.
+Step A must be finished before step B can begin.
+";
+        let mut diagnostics = Diagnostics::new();
+        assert_eq!(solve_with(input, DEFAULT_WORKERS, DEFAULT_BASE_DURATION, &mut diagnostics), Ok(63 + 61 + 62));
+        assert_eq!(diagnostics.get("critical_path"), Some(&"CAB".to_string().into()));
+    }
+}
@@ -0,0 +1,68 @@
+use std::collections::HashMap;
+use std::fmt::Write;
+
+use super::assembly_time;
+use super::graph::GraphNode;
+
+const HIGHLIGHT_FILL: &str = "#f4a582";
+const HIGHLIGHT_EDGE: &str = "#b2182b";
+
+/// Step graph in the Graphviz DOT format, labelled with the step durations,
+/// with the critical path highlighted.
+pub fn to_dot(graph: &HashMap<char, GraphNode>, base_duration: u32) -> String {
+    let path = assembly_time::critical_path(graph, base_duration);
+    let on_path = |a: char, b: char| path.windows(2).any(|w| w[0] == a && w[1] == b);
+    let mut ids: Vec<char> = graph.keys().cloned().collect();
+    ids.sort();
+
+    let mut dot = String::from("digraph steps {\n    rankdir=LR;\n");
+    for id in &ids {
+        let label = format!("{} ({})", id, assembly_time::step_duration(*id, base_duration));
+        if path.contains(id) {
+            writeln!(dot, "    {} [label=\"{}\", style=filled, fillcolor=\"{}\"];", id, label, HIGHLIGHT_FILL).unwrap();
+        } else {
+            writeln!(dot, "    {} [label=\"{}\"];", id, label).unwrap();
+        }
+    }
+    for id in &ids {
+        for next_id in &graph[id].edges_to {
+            if on_path(*id, *next_id) {
+                writeln!(dot, "    {} -> {} [color=\"{}\", penwidth=2];", id, next_id, HIGHLIGHT_EDGE).unwrap();
+            } else {
+                writeln!(dot, "    {} -> {};", id, next_id).unwrap();
+            }
+        }
+    }
+    dot.push_str("}\n");
+    return dot;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use super::super::graph;
+
+    #[test]
+    fn example_dot() {
+        let graph = graph::parse_graph(graph::EXAMPLE).unwrap();
+        let expected = "\
+digraph steps {
+    rankdir=LR;
+    A [label=\"A (1)\"];
+    B [label=\"B (2)\"];
+    C [label=\"C (3)\", style=filled, fillcolor=\"#f4a582\"];
+    D [label=\"D (4)\"];
+    E [label=\"E (5)\", style=filled, fillcolor=\"#f4a582\"];
+    F [label=\"F (6)\", style=filled, fillcolor=\"#f4a582\"];
+    A -> B;
+    A -> D;
+    B -> E;
+    C -> A;
+    C -> F [color=\"#b2182b\", penwidth=2];
+    D -> E;
+    F -> E [color=\"#b2182b\", penwidth=2];
+}
+";
+        assert_eq!(to_dot(&graph, 0), expected);
+    }
+}
@@ -0,0 +1,154 @@
+use std::collections::BTreeSet;
+use std::collections::HashMap;
+
+use crate::parse;
+use crate::parse::LineParser;
+use crate::parse::ParseError;
+
+#[derive(Debug)]
+pub struct GraphNode {
+    pub id: char,
+    pub edges_to: BTreeSet<char>,
+    pub num_edges_from: usize,
+}
+
+pub fn create_graph_node(id: char) -> GraphNode {
+    return GraphNode {id, edges_to: BTreeSet::new(), num_edges_from: 0}
+}
+
+/// Parses `Step A must be finished before step B can begin.`
+fn parse_dependency(line: &mut LineParser) -> Result<(char, char), ParseError> {
+    line.skip_whitespace();
+    line.literal("Step ")?;
+    let a = line.char_matching("step letter", |c| c.is_ascii_uppercase())?;
+    line.literal(" must be finished before step ")?;
+    let b = line.char_matching("step letter", |c| c.is_ascii_uppercase())?;
+    line.literal(" can begin.")?;
+    line.end()?;
+    return Ok((a, b));
+}
+
+/// Steps of some cycle in the graph, each one waiting for the previous one
+/// and the first one waiting for the last, or `None` if there is no cycle.
+pub fn find_cycle(graph: &HashMap<char, GraphNode>) -> Option<Vec<char>> {
+    let mut ids: Vec<char> = graph.keys().cloned().collect();
+    ids.sort();
+    // Visited steps, mapped to whether they are still on the current path.
+    let mut on_path: HashMap<char, bool> = HashMap::new();
+    let mut path = Vec::new();
+    for id in ids {
+        if on_path.contains_key(&id) {
+            continue;
+        }
+        match visit(graph, id, &mut on_path, &mut path) {
+            Some(cycle) => return Some(cycle),
+            None => {},
+        }
+    }
+    return None;
+}
+
+fn visit(graph: &HashMap<char, GraphNode>, id: char, on_path: &mut HashMap<char, bool>, path: &mut Vec<char>) -> Option<Vec<char>> {
+    on_path.insert(id, true);
+    path.push(id);
+    for next_id in &graph[&id].edges_to {
+        match on_path.get(next_id) {
+            Some(true) => {
+                let start = path.iter().position(|c| c == next_id).unwrap();
+                return Some(path[start..].to_vec());
+            },
+            Some(false) => {},
+            None => match visit(graph, *next_id, on_path, path) {
+                Some(cycle) => return Some(cycle),
+                None => {},
+            },
+        }
+    }
+    path.pop();
+    on_path.insert(id, false);
+    return None;
+}
+
+/// Graph of the steps, with an edge from every step to the steps waiting
+/// for it. Cyclic dependencies are reported at the line closing the cycle.
+pub fn parse_graph(input: &str) -> Result<HashMap<char, GraphNode>, ParseError> {
+    let mut dependencies: Vec<(char, char)> = Vec::new();
+    let mut line_numbers: Vec<usize> = Vec::new();
+    for mut line in parse::lines(input) {
+        dependencies.push(parse_dependency(&mut line)?);
+        line_numbers.push(line.number());
+    }
+
+    let mut graph: HashMap<char, GraphNode> = HashMap::new();
+
+    for (a, b) in &dependencies {
+        graph.entry(*a).or_insert(create_graph_node(*a));
+        graph.entry(*b).or_insert(create_graph_node(*b));
+    }
+
+    for (a, b) in &dependencies {
+        // A repeated dependency adds no edge, so it must not be counted twice.
+        let is_new = {
+            let a_node: &mut GraphNode = graph.get_mut(a).unwrap();
+            a_node.edges_to.insert(*b)
+        };
+        if is_new {
+            let b_node: &mut GraphNode = graph.get_mut(b).unwrap();
+            b_node.num_edges_from += 1;
+        }
+    }
+
+    match find_cycle(&graph) {
+        Some(cycle) => {
+            let closing = (cycle[cycle.len() - 1], cycle[0]);
+            let index = dependencies.iter().position(|d| *d == closing).unwrap();
+            let steps: Vec<String> = cycle.iter().chain(cycle.first()).map(|c| c.to_string()).collect();
+            return Err(ParseError::new(line_numbers[index], 1, "acyclic dependencies",
+                format!("cycle {}", steps.join(" -> "))));
+        },
+        None => {},
+    }
+    return Ok(graph);
+}
+
+#[cfg(test)]
+pub(super) const EXAMPLE: &str = "\
+Step C must be finished before step A can begin.
+Step C must be finished before step F can begin.
+Step A must be finished before step B can begin.
+Step A must be finished before step D can begin.
+Step B must be finished before step E can begin.
+Step D must be finished before step E can begin.
+Step F must be finished before step E can begin.
+";
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn strict_parsing() {
+        assert!(parse_graph(EXAMPLE).is_ok());
+        for line in [
+            "Step C must be finished before step A can begin",
+            "Step CX must be finished before step A can begin.",
+            "Step c must be finished before step a can begin.",
+            "Step C will be finished before step A can begin.",
+            "Step C must be finished before step A can begin. Now.",
+            "Lots C of words to fool the old parser A here ok",
+        ] {
+            assert_eq!(parse_graph(line).map(|_| ()).unwrap_err().line, 1, "{:?}", line);
+        }
+    }
+
+    #[test]
+    fn cycles() {
+        let input = format!("{}Step E must be finished before step C can begin.\n", EXAMPLE);
+        let err = parse_graph(&input).unwrap_err();
+        // The search starts from A, so C -> A on line 1 closes the cycle.
+        assert_eq!(err.line, 1);
+        assert_eq!(err.found, "cycle A -> B -> E -> C -> A");
+        let err = parse_graph("Step Q must be finished before step Q can begin.\n").unwrap_err();
+        assert_eq!((err.line, err.found.as_str()), (1, "cycle Q -> Q"));
+    }
+}
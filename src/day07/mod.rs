@@ -0,0 +1,4 @@
+pub mod assembly_time;
+pub mod dot;
+pub mod graph;
+pub mod step_order;
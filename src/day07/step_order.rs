@@ -0,0 +1,58 @@
+use std::collections::BTreeSet;
+
+use crate::parse::ParseError;
+
+use super::graph;
+use super::graph::GraphNode;
+
+pub fn solve(input: &str) -> Result<String, ParseError> {
+    let mut graph = graph::parse_graph(input)?;
+
+    let mut start_node_ids: BTreeSet<char> = graph.values().filter(|n| n.num_edges_from == 0).map(|n| n.id).collect();
+    let mut order = String::new();
+
+    while !start_node_ids.is_empty() {
+        let node_id: char = start_node_ids.iter().next().cloned().unwrap();
+        start_node_ids.remove(&node_id);
+
+
+        let node_edges: Vec<_> = graph.get(&node_id).unwrap().edges_to.iter().cloned().collect();
+
+        for next_node_id in &node_edges {
+            {
+                let node: &mut GraphNode = graph.get_mut(&node_id).unwrap();
+                node.edges_to.remove(next_node_id);
+            }
+            {
+                let next_node: &mut GraphNode = graph.get_mut(next_node_id).unwrap();
+                next_node.num_edges_from -= 1;
+                if next_node.num_edges_from == 0 {
+                    start_node_ids.insert(*next_node_id);
+                }
+            }
+        }
+
+        order.push(node_id);
+    }
+    return Ok(order);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example() {
+        assert_eq!(solve(graph::EXAMPLE), Ok("CABDFE".to_string()));
+    }
+
+    #[test]
+    fn repeated_dependency() {
+        let input = "\
+Step C must be finished before step A can begin.
+Step C must be finished before step A can begin.
+Step A must be finished before step B can begin.
+";
+        assert_eq!(solve(input), Ok("CAB".to_string()));
+    }
+}
@@ -0,0 +1,56 @@
+use crate::parse;
+use crate::parse::ParseError;
+
+struct VecPos {
+    vec: Vec<usize>,
+    pos: usize,
+}
+impl VecPos {
+    fn next(&mut self) -> Option<usize> {
+        let opt = self.vec.get(self.pos).cloned();
+        self.pos += 1;
+        return opt;
+    }
+}
+
+fn sum_tree_numbers(vec_pos: &mut VecPos) -> Option<usize> {
+    let n: usize = vec_pos.next()?;
+    let m: usize = vec_pos.next()?;
+    let mut sum = 0;
+    for _ in 0..n {
+        sum += sum_tree_numbers(vec_pos)?;
+    }
+    for _ in 0..m {
+        sum += vec_pos.next()?;
+    }
+    return Some(sum);
+}
+
+pub fn solve(input: &str) -> Result<usize, ParseError> {
+    let mut numbers: Vec<usize> = Vec::new();
+    let mut end = (1, 1);
+    for mut line in parse::lines(input) {
+        line.skip_whitespace();
+        while !line.rest().is_empty() {
+            numbers.push(line.integer()?);
+            line.skip_whitespace();
+        }
+        end = (line.number(), line.column());
+    }
+
+    let mut vec_pos = VecPos {vec: numbers, pos: 0};
+    match sum_tree_numbers(&mut vec_pos) {
+        Some(sum) => return Ok(sum),
+        None => return Err(ParseError::new(end.0, end.1, "more tree numbers", "end of input")),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example() {
+        assert_eq!(solve("2 3 0 3 10 11 12 1 1 0 1 99 2 1 1 2\n"), Ok(138));
+    }
+}
@@ -0,0 +1 @@
+pub mod metadata_sum;
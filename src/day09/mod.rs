@@ -0,0 +1 @@
+pub mod winning_score;
@@ -1,8 +1,9 @@
-use std::io;
-use std::io::BufRead;
 use std::collections::BTreeSet;
 use std::cmp;
 
+use crate::parse;
+use crate::parse::ParseError;
+
 #[derive(Debug)]
 struct MarbleCircle{
     numbers: Vec<usize>,
@@ -54,15 +55,20 @@ impl MarbleCircle {
     }
 }
 
-fn main() {
-    let stdin = io::stdin();
-    let line: String = stdin.lock().lines().next().unwrap().expect("missing line");
-    let segments: Vec<&str> = line.trim().split(' ').collect();
-    let num_of_players: usize = segments[0].parse().expect("not an integer");
-    let max_marble_number: usize = segments[6].parse().expect("not an integer");
-    assert!(num_of_players > 0);
-    let mut player_scores = Vec::new();
-    player_scores.resize(num_of_players, 0);
+pub fn solve(input: &str) -> Result<usize, ParseError> {
+    let mut line = match parse::lines(input).next() {
+        Some(line) => line,
+        None => return Err(ParseError::new(1, 1, "game description", "end of input")),
+    };
+    let num_of_players: usize = line.integer()?;
+    if num_of_players == 0 {
+        return Err(line.error_at(1, "positive number of players", "0"));
+    }
+    line.literal(" players; last marble is worth ")?;
+    let max_marble_number: usize = line.integer()?;
+    line.literal(" points")?;
+    line.end()?;
+    let mut player_scores = vec![0; num_of_players];
     let mut current_player_index = 0;
     let mut marble_numbers: BTreeSet<usize> = (1..=max_marble_number).collect();
     let mut circle = MarbleCircle::new();
@@ -70,7 +76,7 @@ fn main() {
     while !marble_numbers.is_empty() {
         let marble_num: usize = *marble_numbers.iter().next().unwrap();
         marble_numbers.remove(&marble_num);
-        if marble_num % 23 == 0 {
+        if marble_num.is_multiple_of(23) {
             let old_selected_index = circle.get_selected_index();
             let inc = marble_num + circle.get(old_selected_index - 7);
             player_scores[current_player_index] += inc;
@@ -83,5 +89,24 @@ fn main() {
         current_player_index = (current_player_index + 1) % num_of_players;
     }
     let best_score = player_scores.iter().fold(0, |m, el| cmp::max(m, *el));
-    println!("{}", best_score);
+    return Ok(best_score);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn game(num_of_players: usize, last_marble: usize) -> String {
+        format!("{} players; last marble is worth {} points\n", num_of_players, last_marble)
+    }
+
+    #[test]
+    fn examples() {
+        assert_eq!(solve(&game(9, 25)), Ok(32));
+        assert_eq!(solve(&game(10, 1618)), Ok(8317));
+        assert_eq!(solve(&game(13, 7999)), Ok(146373));
+        assert_eq!(solve(&game(17, 1104)), Ok(2764));
+        assert_eq!(solve(&game(21, 6111)), Ok(54718));
+        assert_eq!(solve(&game(30, 5807)), Ok(37305));
+    }
 }
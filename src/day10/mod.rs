@@ -0,0 +1 @@
+pub mod stars_alignment;
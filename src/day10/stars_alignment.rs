@@ -0,0 +1,186 @@
+use crate::diagnostics::Diagnostics;
+use crate::geometry::BoundingBox;
+use crate::geometry::Coord;
+use crate::geometry::Point2;
+use crate::parse;
+use crate::parse::LineParser;
+use crate::parse::ParseError;
+
+/// Number of frames written by default.
+pub const DEFAULT_ITERATIONS: usize = 1000;
+
+/// Image of the stars within given bounds, every pixel covering `stride`
+/// by `stride` positions.
+fn frame_image(bounds: &BoundingBox<2>, positions: &[Point2], stride: usize) -> image::GrayImage {
+    let extent = bounds.extent();
+    let width = ((extent.x() - 1) as u32) / stride as u32 + 1;
+    let height = ((extent.y() - 1) as u32) / stride as u32 + 1;
+    let mut img = image::GrayImage::new(width, height);
+    for p in positions.iter() {
+        let c = (*p - bounds.min) / stride as Coord;
+        if 0 <= c.x() && c.x() < width as Coord && 0 <= c.y() && c.y() < height as Coord {
+            img.put_pixel(c.x() as u32, c.y() as u32, image::Luma([255u8]));
+        }
+    }
+    return img;
+}
+
+fn parse_vector(line: &mut LineParser) -> Result<Point2, ParseError> {
+    line.literal("<")?;
+    let x = line.integer()?;
+    line.literal(",")?;
+    let y = line.integer()?;
+    line.skip_whitespace();
+    line.literal(">")?;
+    return Ok(Point2::new([x, y]));
+}
+
+fn read_input(input: &str) -> Result<(Vec<Point2>, Vec<Point2>), ParseError> {
+    let mut positions: Vec<Point2> = Vec::new();
+    let mut velocities: Vec<Point2> = Vec::new();
+    for mut line in parse::lines(input) {
+        line.skip_whitespace();
+        line.literal("position=")?;
+        positions.push(parse_vector(&mut line)?);
+        line.skip_whitespace();
+        line.literal("velocity=")?;
+        velocities.push(parse_vector(&mut line)?);
+        line.end()?;
+    }
+    if positions.is_empty() {
+        return Err(ParseError::new(1, 1, "star description", "end of input"));
+    }
+    return Ok((positions, velocities));
+}
+
+fn get_bounds(positions: &[Point2]) -> BoundingBox<2> {
+    BoundingBox::from_points(positions).expect("at least one star")
+}
+
+/// Frames of the sky every `stride` seconds, starting from second `start`.
+/// All of them cover the bounds of the stars at the start, scaled down by
+/// `stride`.
+pub struct Frames {
+    positions: Vec<Point2>,
+    velocities: Vec<Point2>,
+    bounds: BoundingBox<2>,
+    stride: usize,
+}
+impl Iterator for Frames {
+    type Item = image::GrayImage;
+
+    fn next(&mut self) -> Option<image::GrayImage> {
+        let img = frame_image(&self.bounds, &self.positions, self.stride);
+        let stride = self.stride as Coord;
+        for (p, v) in self.positions.iter_mut().zip(self.velocities.iter()) {
+            *p += *v * stride;
+        }
+        return Some(img);
+    }
+}
+
+pub fn frames(input: &str, start: usize, stride: usize, diagnostics: &mut Diagnostics) -> Result<Frames, ParseError> {
+    let (mut positions, velocities) = read_input(input)?;
+    positions = positions.iter().zip(velocities.iter()).map(|(p, v)| *p + *v * start as Coord).collect();
+    let bounds = get_bounds(&positions);
+    diagnostics.trace(|| format!("start: {}, stride: {}, {}x{} (unscaled)", start, stride, bounds.extent().x(), bounds.extent().y()));
+    return Ok(Frames { positions, velocities, bounds, stride });
+}
+
+pub fn solve(input: &str) -> Result<String, ParseError> {
+    let (mut positions, velocities) = read_input(input)?;
+
+    // The message appears when the stars are the most condensed vertically.
+    let mut bounds = get_bounds(&positions);
+    loop {
+        let next_positions: Vec<Point2> = positions.iter().zip(velocities.iter()).map(|(p, v)| *p + *v).collect();
+        let next_bounds = get_bounds(&next_positions);
+        if next_bounds.extent().y() >= bounds.extent().y() {
+            break;
+        }
+        positions = next_positions;
+        bounds = next_bounds;
+    }
+
+    let mut message = String::new();
+    for y in bounds.min.y()..=bounds.max.y() {
+        for x in bounds.min.x()..=bounds.max.x() {
+            message.push(if positions.contains(&Point2::new([x, y])) { '#' } else { '.' });
+        }
+        message.push('\n');
+    }
+    return Ok(message);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+position=< 9,  1> velocity=< 0,  2>
+position=< 7,  0> velocity=<-1,  0>
+position=< 3, -2> velocity=<-1,  1>
+position=< 6, 10> velocity=<-2, -1>
+position=< 2, -4> velocity=< 2,  2>
+position=<-6, 10> velocity=< 2, -2>
+position=< 1,  8> velocity=< 1, -1>
+position=< 1,  7> velocity=< 1,  0>
+position=<-3, 11> velocity=< 1, -2>
+position=< 7,  6> velocity=<-1, -1>
+position=<-2,  3> velocity=< 1,  0>
+position=<-4,  3> velocity=< 2,  0>
+position=<10, -3> velocity=<-1,  1>
+position=< 5, 11> velocity=< 1, -2>
+position=< 4,  7> velocity=< 0, -1>
+position=< 8, -2> velocity=< 0,  1>
+position=<15,  0> velocity=<-2,  0>
+position=< 1,  6> velocity=< 1,  0>
+position=< 8,  9> velocity=< 0, -1>
+position=< 3,  3> velocity=<-1,  1>
+position=< 0,  5> velocity=< 0, -1>
+position=<-2,  2> velocity=< 2,  0>
+position=< 5, -2> velocity=< 1,  2>
+position=< 1,  4> velocity=< 2,  1>
+position=<-2,  7> velocity=< 2, -2>
+position=< 3,  6> velocity=<-1, -1>
+position=< 5,  0> velocity=< 1,  0>
+position=<-6,  0> velocity=< 2,  0>
+position=< 5,  9> velocity=< 1, -2>
+position=<14,  7> velocity=<-2,  0>
+position=<-3,  6> velocity=< 2, -1>
+";
+
+    const MESSAGE: &str = "\
+#...#..###
+#...#...#.
+#...#...#.
+#####...#.
+#...#...#.
+#...#...#.
+#...#...#.
+#...#..###
+";
+
+    #[test]
+    fn example() {
+        assert_eq!(solve(EXAMPLE), Ok(MESSAGE.to_string()));
+    }
+
+    #[test]
+    fn example_frames() {
+        let mut frames = frames(EXAMPLE, 2, 1, &mut Diagnostics::new()).unwrap();
+        let before = frames.next().unwrap();
+        assert_eq!(before.dimensions(), (14, 10));
+        // The message at second 3 keeps the bounds of second 2, from -2,-1.
+        let message = frames.next().unwrap();
+        assert_eq!(message.dimensions(), (14, 10));
+        for (y, row) in MESSAGE.lines().enumerate() {
+            for (x, c) in row.chars().enumerate() {
+                let lit = message.get_pixel(x as u32 + 2, y as u32 + 1).0 == [255];
+                assert_eq!(lit, c == '#');
+            }
+        }
+        let scaled = super::frames(EXAMPLE, 3, 2, &mut Diagnostics::new()).unwrap().next().unwrap();
+        assert_eq!(scaled.dimensions(), (5, 4));
+    }
+}
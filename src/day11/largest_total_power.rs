@@ -0,0 +1,98 @@
+use crate::diagnostics::Diagnostics;
+use crate::grid::Grid;
+use crate::parse;
+use crate::parse::ParseError;
+
+fn calculate_cell_power_level(x: i32, y: i32, serial_number: i32) -> i32 {
+    let rack_id = x + 10;
+    let power_level1 = rack_id * y;
+    let power_level2 = power_level1 + serial_number;
+    let power_level3 = power_level2 * rack_id;
+    let power_level4 = (power_level3 / 100) % 10;
+    let power_level5 = power_level4 - 5;
+    return power_level5;
+}
+
+/// Returns the top-left corner of the 3x3 square with the largest total power
+/// together with that power.
+pub fn find_largest_total_power(serial_number: i32) -> ((i32, i32), i32) {
+    let mut map = Grid::new(300, 300, 0);
+
+    for x in 1i32..=300 {
+        for y in 1i32..=300 {
+            let power_level = calculate_cell_power_level(x, y, serial_number);
+            map[((x - 1) as usize, (y - 1) as usize)] = power_level;
+        }
+    }
+
+    let mut best_x = -1;
+    let mut best_y = -1;
+    let mut best_sum = -9 * 9;
+
+    for x in 1i32..=300-2 {
+        for y in 1i32..=300-2 {
+            let mut sum = 0;
+            for i in 0..3 {
+                for j in 0..3 {
+                    sum += map[((x + i - 1) as usize, (y + j - 1) as usize)];
+                }
+            }
+
+            if sum > best_sum {
+                best_sum = sum;
+                best_x = x;
+                best_y = y;
+            }
+        }
+    }
+    return ((best_x, best_y), best_sum);
+}
+
+pub fn solve(input: &str) -> Result<(i32, i32), ParseError> {
+    return solve_with(input, &mut Diagnostics::new());
+}
+
+/// Like `solve`, also reporting the total power of the square.
+pub fn solve_with(input: &str, diagnostics: &mut Diagnostics) -> Result<(i32, i32), ParseError> {
+    let mut line = match parse::lines(input).next() {
+        Some(line) => line,
+        None => return Err(ParseError::new(1, 1, "serial number", "end of input")),
+    };
+    let serial_number: i32 = line.integer()?;
+    line.end()?;
+    let (coords, total_power) = find_largest_total_power(serial_number);
+    diagnostics.set("total_power", total_power);
+    return Ok(coords);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cell_power_levels() {
+        assert_eq!(calculate_cell_power_level(3, 5, 8), 4);
+        assert_eq!(calculate_cell_power_level(122, 79, 57), -5);
+        assert_eq!(calculate_cell_power_level(217, 196, 39), 0);
+        assert_eq!(calculate_cell_power_level(101, 153, 71), 4);
+    }
+
+    #[test]
+    fn largest_total_power() {
+        assert_eq!(find_largest_total_power(18), ((33, 45), 29));
+        assert_eq!(find_largest_total_power(42), ((21, 61), 30));
+    }
+
+    #[test]
+    fn examples() {
+        assert_eq!(solve("18\n"), Ok((33, 45)));
+        assert_eq!(solve("42\n"), Ok((21, 61)));
+    }
+
+    #[test]
+    fn diagnostics_report_total_power() {
+        let mut diagnostics = Diagnostics::new();
+        assert_eq!(solve_with("18\n", &mut diagnostics), Ok((33, 45)));
+        assert_eq!(diagnostics.get("total_power"), Some(&29.into()));
+    }
+}
@@ -0,0 +1 @@
+pub mod largest_total_power;
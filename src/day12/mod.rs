@@ -0,0 +1 @@
+pub mod plants_sustainability;
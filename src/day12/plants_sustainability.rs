@@ -0,0 +1,209 @@
+use std::cmp;
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::fmt;
+
+use crate::parse;
+use crate::parse::LineParser;
+use crate::parse::ParseError;
+
+type Pattern = [bool; 5];
+
+fn pattern_vec_to_array(pat_vec: &[bool]) -> Pattern {
+    return match *pat_vec {
+        [h1, h2, h3, h4, h5] => [h1, h2, h3, h4, h5],
+        _ => panic!("invalid pattern"),
+    }
+}
+
+struct State {
+    set: HashSet<i32>,
+}
+impl fmt::Debug for State {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let a = self.lower_bound();
+        let b = self.upper_bound();
+        let mut string = String::new();
+        for i in a..=b {
+            string.push(if self.get(i) { '#' } else { '.' });
+        }
+        write!(f, "{}", string)
+    }
+}
+impl State {
+    fn from_vec(vec: &[bool]) -> State {
+        let mut new_state = State { set: HashSet::new() };
+        for (i, el) in vec.iter().enumerate() {
+            new_state.put(i as i32, *el);
+        }
+        return new_state;
+    }
+    fn get(&self, index: i32) -> bool {
+        return self.set.contains(&index);
+    }
+    fn put(&mut self, index: i32, value: bool) {
+        match value {
+            true => self.set.insert(index),
+            false => self.set.remove(&index),
+        };
+        return;
+    }
+    fn get_pattern(&self, index: i32) -> Pattern {
+        let mut pat_vec: Vec<bool> = Vec::new();
+        for i in (index - 2)..=(index + 2) {
+            pat_vec.push(self.get(i));
+        }
+        return pattern_vec_to_array(&pat_vec);
+    }
+    fn lower_bound(&self) -> i32 {
+        return self.set.iter().fold(0, |m, el| cmp::min(m, *el));
+    }
+    fn upper_bound(&self) -> i32 {
+        return self.set.iter().fold(0, |m, el| cmp::max(m, *el));
+    }
+    fn apply_rules(&self, rules: &[Rule]) -> State {
+        let mut rule_map: HashMap<Pattern, bool> = HashMap::new();
+        for rule in rules.iter() {
+            rule_map.insert(rule.head, rule.tail);
+        }
+        let a = self.lower_bound() - 2;
+        let b = self.upper_bound() + 2;
+        let mut new_state = State { set: HashSet::new() };
+
+        for i in a..=b {
+            let pat = self.get_pattern(i);
+            match rule_map.get(&pat) {
+                Some(v) => {
+                    new_state.put(i, *v);
+                },
+                None => {
+                    new_state.put(i, false);
+                },
+            }
+        }
+        return new_state;
+
+    }
+    fn set_index_sum(&self) -> i32 {
+        return self.set.iter().fold(0, |s, el| s + *el);
+    }
+}
+
+#[derive(Debug)]
+struct Rule {
+    head: Pattern,
+    tail: bool,
+}
+
+fn parse_pot(line: &mut LineParser) -> Result<bool, ParseError> {
+    let c = line.char_matching("'#' or '.'", |c| c == '#' || c == '.')?;
+    return Ok(c == '#');
+}
+
+fn read_input(input: &str) -> Result<(State, Vec<Rule>), ParseError> {
+    let mut lines = parse::lines(input);
+    let mut rules: Vec<Rule> = Vec::new();
+    let mut first_line = match lines.next() {
+        Some(line) => line,
+        None => return Err(ParseError::new(1, 1, "initial state", "end of input")),
+    };
+    first_line.literal("initial state: ")?;
+    let mut initial_state_vec: Vec<bool> = Vec::new();
+    while !first_line.rest().trim_end().is_empty() {
+        initial_state_vec.push(parse_pot(&mut first_line)?);
+    }
+    let initial_state = State::from_vec(&initial_state_vec);
+
+    for mut line in lines {
+        if line.is_blank() {
+            continue;
+        }
+        let mut head: Pattern = [false; 5];
+        line.skip_whitespace();
+        for h in head.iter_mut() {
+            *h = parse_pot(&mut line)?;
+        }
+        line.skip_whitespace();
+        line.literal("=>")?;
+        line.skip_whitespace();
+        let tail = parse_pot(&mut line)?;
+        line.end()?;
+        rules.push(Rule { head, tail });
+    }
+    return Ok((initial_state, rules));
+}
+
+pub fn solve(input: &str) -> Result<i32, ParseError> {
+    let (mut state, rules) = read_input(input)?;
+    for _ in 0..20 {
+        state = state.apply_rules(&rules);
+    }
+    return Ok(state.set_index_sum());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+initial state: #..#.#..##......###...###
+
+...## => #
+..#.. => #
+.#... => #
+.#.#. => #
+.#.## => #
+.##.. => #
+.#### => #
+#.#.# => #
+#.### => #
+##.#. => #
+##.## => #
+###.. => #
+###.# => #
+####. => #
+";
+
+    /// Generations 0 to 20 from the puzzle statement, pots -3 to 35.
+    const GENERATIONS: [&str; 21] = [
+        "...#..#.#..##......###...###...........",
+        "...#...#....#.....#..#..#..#...........",
+        "...##..##...##....#..#..#..##..........",
+        "..#.#...#..#.#....#..#..#...#..........",
+        "...#.#..#...#.#...#..#..##..##.........",
+        "....#...##...#.#..#..#...#...#.........",
+        "....##.#.#....#...#..##..##..##........",
+        "...#..###.#...##..#...#...#...#........",
+        "...#....##.#.#.#..##..##..##..##.......",
+        "...##..#..#####....#...#...#...#.......",
+        "..#.#..#...#.##....##..##..##..##......",
+        "...#...##...#.#...#.#...#...#...#......",
+        "...##.#.#....#.#...#.#..##..##..##.....",
+        "..#..###.#....#.#...#....#...#...#.....",
+        "..#....##.#....#.#..##...##..##..##....",
+        "..##..#..#.#....#....#..#.#...#...#....",
+        ".#.#..#...#.#...##...#...#.#..##..##...",
+        "..#...##...#.#.#.#...##...#....#...#...",
+        "..##.#.#....#####.#.#.#...##...##..##..",
+        ".#..###.#..#.#.#######.#.#.#..#.#...#..",
+        ".#....##....#####...#######....#.#..##.",
+    ];
+
+    fn render(state: &State) -> String {
+        (-3..=35).map(|i| if state.get(i) { '#' } else { '.' }).collect()
+    }
+
+    #[test]
+    fn example_generations() {
+        let (mut state, rules) = read_input(EXAMPLE).unwrap();
+        for (generation, expected) in GENERATIONS.iter().enumerate() {
+            assert_eq!(render(&state), *expected, "generation {}", generation);
+            state = state.apply_rules(&rules);
+        }
+    }
+
+    #[test]
+    fn example() {
+        assert_eq!(solve(EXAMPLE), Ok(325));
+    }
+}
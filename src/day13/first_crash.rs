@@ -0,0 +1,268 @@
+use std::collections::BTreeMap;
+use std::convert::Infallible;
+
+use crate::grid::Grid;
+use crate::parse::ParseError;
+
+#[derive(PartialEq, Eq, Clone, Debug)]
+enum Edge {
+    Left,
+    Top,
+    Right,
+    Bottom,
+}
+
+fn flip_edge(edge: Edge) -> Edge {
+    match edge {
+        Edge::Left => Edge::Right,
+        Edge::Top => Edge::Bottom,
+        Edge::Right => Edge::Left,
+        Edge::Bottom => Edge::Top,
+    }
+}
+
+/// Offset of the neighbouring field across given edge.
+fn edge_delta(edge: &Edge) -> (isize, isize) {
+    match edge {
+        Edge::Left => (-1, 0),
+        Edge::Top => (0, -1),
+        Edge::Right => (1, 0),
+        Edge::Bottom => (0, 1),
+    }
+}
+
+fn rotate_edge_cw(edge: Edge) -> Edge {
+    match edge {
+        Edge::Left => Edge::Top,
+        Edge::Top => Edge::Right,
+        Edge::Right => Edge::Bottom,
+        Edge::Bottom => Edge::Left,
+    }
+}
+
+fn rotate_edge_ccw(edge: Edge) -> Edge {
+    match edge {
+        Edge::Left => Edge::Bottom,
+        Edge::Top => Edge::Left,
+        Edge::Right => Edge::Top,
+        Edge::Bottom => Edge::Right,
+    }
+}
+
+#[derive(PartialEq, Eq, Clone)]
+enum CartState {
+    GoLeft,
+    GoStraight,
+    GoRight,
+}
+
+fn next_cart_state(state: CartState) -> CartState {
+    match state {
+        CartState::GoLeft => CartState::GoStraight,
+        CartState::GoStraight => CartState::GoRight,
+        CartState::GoRight => CartState::GoLeft,
+    }
+}
+
+#[derive(PartialEq, Eq, Clone)]
+enum MapCell {
+    Nothing,
+    Crossing,
+    Segment(Edge, Edge),
+}
+
+type Map = Grid<MapCell>;
+
+struct Cart {
+    x: usize,
+    y: usize,
+    direction: Edge,
+    crossing_state: CartState,
+}
+impl Cart {
+    fn new(x: usize, y: usize, direction: Edge) -> Cart {
+        Cart { x, y, direction, crossing_state: CartState::GoLeft }
+    }
+    /// Moves the cart by one field. Fails with the cart's position, leaving
+    /// the cart in place, if the track does not continue there.
+    fn step(&mut self, map: &Map) -> Result<(), (usize, usize)> {
+        let (delta_x, delta_y) = edge_delta(&self.direction);
+        let next_x = self.x as isize + delta_x;
+        let next_y = self.y as isize + delta_y;
+        if next_x < 0 || next_y < 0 || next_x as usize >= map.width() || next_y as usize >= map.height() {
+            return Err((self.x, self.y));
+        }
+        let (next_x, next_y) = (next_x as usize, next_y as usize);
+        let next_cell: &MapCell = &map[(next_x, next_y)];
+        match next_cell {
+            MapCell::Nothing => return Err((self.x, self.y)),
+            MapCell::Crossing => {
+                let next_direction: Edge = match self.crossing_state {
+                    CartState::GoLeft => rotate_edge_ccw(self.direction.clone()),
+                    CartState::GoStraight => self.direction.clone(),
+                    CartState::GoRight => rotate_edge_cw(self.direction.clone()),
+                };
+                let next_crossing_state = next_cart_state(self.crossing_state.clone());
+                self.direction = next_direction;
+                self.crossing_state = next_crossing_state;
+            },
+            MapCell::Segment(_, _) => {
+                match get_another_edge(next_cell, flip_edge(self.direction.clone())) {
+                    Some(next_direction) => self.direction = next_direction,
+                    None => return Err((self.x, self.y)),
+                }
+            }
+        }
+        self.x = next_x;
+        self.y = next_y;
+        return Ok(());
+    }
+    fn get_order_key(&self) -> (usize, usize) {
+        return (self.y, self.x);
+    }
+}
+
+fn has_edge(cell: &MapCell, edge: Edge) -> bool {
+    match cell {
+        MapCell::Nothing => false,
+        MapCell::Crossing => true,
+        MapCell::Segment(e1, e2) => edge == *e1 || edge == *e2,
+    }
+}
+
+fn get_another_edge(cell: &MapCell, edge: Edge) -> Option<Edge> {
+    match cell {
+        MapCell::Nothing => None,
+        MapCell::Crossing => None,
+        MapCell::Segment(e1, e2) => {
+            if edge == *e1 {
+                Some(e2.clone())
+            } else if edge == *e2 {
+                Some(e1.clone())
+            } else {
+                None
+            }
+        }
+    }
+}
+
+fn has_bottom_edge(cell: &MapCell) -> bool {
+    return has_edge(cell, Edge::Bottom);
+}
+
+pub fn solve(input: &str) -> Result<(usize, usize), ParseError> {
+    let chars: Grid<char> = Grid::parse(input, |_, c| Ok::<char, Infallible>(c)).unwrap();
+    let mut map: Map = Grid::new(chars.width(), chars.height(), MapCell::Nothing);
+    let mut carts: Vec<Cart> = Vec::new();
+    let vertical = MapCell::Segment(Edge::Top, Edge::Bottom);
+    let horizontal = MapCell::Segment(Edge::Left, Edge::Right);
+    for (x, y) in chars.positions() {
+        let c = chars[(x, y)];
+        let upper_cell: &MapCell = if y > 0 {
+            &map[(x, y - 1)]
+        } else {
+            &MapCell::Nothing
+        };
+        let cell: MapCell = match c {
+            ' ' => MapCell::Nothing,
+            '|' => vertical.clone(),
+            '^' => vertical.clone(),
+            'v' => vertical.clone(),
+            '-' => horizontal.clone(),
+            '>' => horizontal.clone(),
+            '<' => horizontal.clone(),
+            '/' => {
+                if has_bottom_edge(upper_cell) {
+                    MapCell::Segment(Edge::Left, Edge::Top)
+                } else {
+                    MapCell::Segment(Edge::Right, Edge::Bottom)
+                }
+            },
+            '\\' => {
+                if has_bottom_edge(upper_cell) {
+                    MapCell::Segment(Edge::Right, Edge::Top)
+                } else {
+                    MapCell::Segment(Edge::Left, Edge::Bottom)
+                }
+            },
+            '+' => MapCell::Crossing,
+            _ => return Err(ParseError::new(y + 1, x + 1, "track or cart", format!("{:?}", c))),
+        };
+        map[(x, y)] = cell;
+
+        let cart_direction = match c {
+            '^' => Some(Edge::Top),
+            'v' => Some(Edge::Bottom),
+            '>' => Some(Edge::Right),
+            '<' => Some(Edge::Left),
+            _ => None,
+        };
+        match cart_direction {
+            None => {}
+            Some(direction) => {
+                carts.push(Cart::new(x, y, direction));
+            }
+        }
+    }
+
+    if carts.len() < 2 {
+        return Err(ParseError::new(1, 1, "at least two carts", carts.len().to_string()));
+    }
+
+    let mut crash_point = None;
+
+    let mut cart_index_order_map: BTreeMap<(usize, usize), usize> = BTreeMap::new();
+    for (i, cart) in carts.iter().enumerate() {
+        cart_index_order_map.insert(cart.get_order_key(), i);
+    }
+    while crash_point.is_none() {
+        let cart_index_order: Vec<usize> = cart_index_order_map.values().cloned().collect();
+        for i in cart_index_order {
+            let cart = carts.get_mut(i).unwrap();
+            let old_order_key: (usize, usize) = cart.get_order_key();
+            cart.step(&map).map_err(|(x, y)| {
+                ParseError::new(y + 1, x + 1, "connected track", format!("{:?}", chars[(x, y)]))
+            })?;
+            let new_order_key: (usize, usize) = cart.get_order_key();
+            cart_index_order_map.remove(&old_order_key);
+            if cart_index_order_map.contains_key(&new_order_key){
+                crash_point = Some((cart.x, cart.y));
+                break;
+            }
+            cart_index_order_map.insert(new_order_key, i);
+        }
+    }
+    return Ok(crash_point.unwrap());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn straight_track() {
+        let input = "|\nv\n|\n|\n|\n^\n|\n";
+        assert_eq!(solve(input), Ok((0, 3)));
+    }
+
+    #[test]
+    fn example() {
+        let input = [
+            r"/->-\        ",
+            r"|   |  /----\",
+            r"| /-+--+-\  |",
+            r"| | |  | v  |",
+            r"\-+-/  \-+--/",
+            r"  \------/   ",
+        ].join("\n");
+        assert_eq!(solve(&input), Ok((7, 3)));
+    }
+
+    #[test]
+    fn broken_track() {
+        assert_eq!(solve("->  <-"), Err(ParseError::new(1, 2, "connected track", "'>'")));
+        assert_eq!(solve("<>"), Err(ParseError::new(1, 1, "connected track", "'<'")));
+        assert_eq!(solve("/-\\\n|  \n\\-/ \n^ v"), Err(ParseError::new(4, 1, "connected track", "'^'")));
+        assert_eq!(solve("/>\\\n\\-/"), Err(ParseError::new(1, 1, "at least two carts", "1")));
+    }
+}
@@ -0,0 +1,114 @@
+use elfcode::Instruction;
+use elfcode::OpCode;
+use elfcode::Registers;
+
+use crate::parse;
+use crate::parse::LineParser;
+use crate::parse::ParseError;
+
+type InstructionCode = [usize; 4];
+
+const NUM_OF_REGISTERS: usize = 4;
+
+fn parse_registers(line: &mut LineParser, prefix: &str) -> Result<Registers, ParseError> {
+    line.literal(prefix)?;
+    line.skip_whitespace();
+    line.literal("[")?;
+    let mut vec: Vec<usize> = Vec::new();
+    for i in 0..NUM_OF_REGISTERS {
+        if i > 0 {
+            line.literal(",")?;
+        }
+        vec.push(line.integer()?);
+    }
+    line.literal("]")?;
+    line.end()?;
+    return Ok(Registers::from(vec));
+}
+
+fn parse_instruction_code(line: &mut LineParser) -> Result<InstructionCode, ParseError> {
+    let mut code: InstructionCode = [0; 4];
+    for value in code.iter_mut() {
+        *value = line.integer()?;
+    }
+    line.end()?;
+    return Ok(code);
+}
+
+fn line_option_is_empty(line_input: &Option<LineParser>) -> bool {
+    match line_input {
+        None => true,
+        Some(line) => line.is_blank(),
+    }
+}
+
+pub fn solve(input: &str) -> Result<usize, ParseError> {
+    let mut lines_iter = parse::lines(input);
+    let mut counter = 0;
+    let op_codes = OpCode::values();
+    loop {
+        let before_line_opt = lines_iter.next();
+        let codes_opt = lines_iter.next();
+        let after_line_opt = lines_iter.next();
+        lines_iter.next();
+        if line_option_is_empty(&before_line_opt) {
+            break;
+        }
+        if line_option_is_empty(&codes_opt) {
+            break;
+        }
+        if line_option_is_empty(&after_line_opt) {
+            break;
+        }
+        let reg_before = parse_registers(&mut before_line_opt.unwrap(), "Before:")?;
+        let instruction_code = parse_instruction_code(&mut codes_opt.unwrap())?;
+        let reg_after = parse_registers(&mut after_line_opt.unwrap(), "After:")?;
+
+        let mut op_code_match_counter = 0;
+
+        for op_code in &op_codes {
+            let [_, a, b, c] = instruction_code;
+            let instruction = Instruction::new(*op_code, a, b, c);
+            match instruction.eval(&reg_before) {
+                Err(_) => {}
+                Ok(reg_output) => {
+                    if reg_output == reg_after {
+                        op_code_match_counter += 1;
+                    }
+                }
+            }
+        }
+
+        if op_code_match_counter >= 3 {
+            counter += 1;
+        }
+    }
+    return Ok(counter);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+Before: [3, 2, 1, 1]
+9 2 1 2
+After:  [3, 2, 2, 1]
+";
+
+    #[test]
+    fn example_behaves_like_three_opcodes() {
+        let reg_before = Registers::from(vec![3, 2, 1, 1]);
+        let reg_after = Registers::from(vec![3, 2, 2, 1]);
+        let mut matching: Vec<OpCode> = OpCode::values().into_iter()
+            .filter(|op_code| Instruction::new(*op_code, 2, 1, 2).eval(&reg_before) == Ok(reg_after.clone()))
+            .collect();
+        matching.sort_by_key(|op_code| op_code.name());
+        assert_eq!(matching, vec![OpCode::AddI, OpCode::MulR, OpCode::SetI]);
+    }
+
+    #[test]
+    fn example() {
+        assert_eq!(solve(EXAMPLE), Ok(1));
+    }
+}
@@ -0,0 +1 @@
+pub mod guess_opcodes;
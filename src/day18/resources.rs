@@ -0,0 +1,280 @@
+use std::collections::HashMap;
+use std::fmt;
+
+use crate::grid::Grid;
+use crate::grid::Pos;
+use crate::grid::ToChar;
+use crate::parse::ParseError;
+
+#[derive(PartialEq, Eq, Hash, Clone)]
+enum Field {
+    OpenGround,
+    Trees,
+    Lumberyard,
+}
+impl Field {
+    fn from_char(c: char) -> Option<Field> {
+        match c {
+            '.' => Some(Field::OpenGround),
+            '|' => Some(Field::Trees),
+            '#' => Some(Field::Lumberyard),
+            _ => None,
+        }
+    }
+}
+impl ToChar for Field {
+    fn to_char(&self) -> char {
+        match self {
+            Field::OpenGround => '.',
+            Field::Trees => '|',
+            Field::Lumberyard => '#',
+        }
+    }
+}
+impl fmt::Debug for Field {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.to_char())
+    }
+}
+
+type State = Grid<Field>;
+
+fn load_initial_state(input: &str) -> Result<State, ParseError> {
+    return Grid::parse(input, |(x, y), c| {
+        Field::from_char(c).ok_or_else(|| ParseError::new(y + 1, x + 1, "'.', '|' or '#'", format!("{:?}", c)))
+    });
+}
+
+fn count_neighborhood_fields(state: &State, pos: Pos) -> HashMap<Field, usize> {
+    let mut counters = HashMap::new();
+
+    counters.insert(Field::OpenGround, 0);
+    counters.insert(Field::Trees, 0);
+    counters.insert(Field::Lumberyard, 0);
+
+    for neighbour_pos in state.neighbours8(pos) {
+        let counter: &mut usize = counters.get_mut(&state[neighbour_pos]).unwrap();
+        *counter += 1;
+    }
+    return counters;
+}
+
+fn get_new_state(state: &State) -> State {
+    let mut new_state = Grid::new(state.width(), state.height(), Field::OpenGround);
+    for pos in state.positions() {
+        let counters = count_neighborhood_fields(state, pos);
+        let new_field = match state[pos] {
+            Field::OpenGround => {
+                if counters[&Field::Trees] >= 3 {
+                    Field::Trees
+                } else {
+                    Field::OpenGround
+                }
+            },
+            Field::Trees => {
+                if counters[&Field::Lumberyard] >= 3 {
+                    Field::Lumberyard
+                } else {
+                    Field::Trees
+                }
+            },
+            Field::Lumberyard => {
+                if counters[&Field::Lumberyard] >= 1 && counters[&Field::Trees] >= 1 {
+                    Field::Lumberyard
+                } else {
+                    Field::OpenGround
+                }
+            },
+        };
+        new_state[pos] = new_field;
+    }
+
+    return new_state;
+}
+
+fn calculate_resource_index(state: &State) -> usize {
+    let mut num_of_trees = 0;
+    let mut num_of_lumberyards = 0;
+    for field in state.iter() {
+        match *field {
+            Field::OpenGround => {},
+            Field::Trees => {
+                num_of_trees += 1;
+            },
+            Field::Lumberyard => {
+                num_of_lumberyards += 1;
+            },
+        }
+    }
+
+    return num_of_trees * num_of_lumberyards;
+}
+
+pub fn solve(input: &str) -> Result<usize, ParseError> {
+    let initial_state = load_initial_state(input)?;
+
+    let mut state = initial_state;
+
+    for _ in 0..10 {
+        state = get_new_state(&state);
+    }
+    return Ok(calculate_resource_index(&state));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+.#.#...|#.
+.....#|##|
+.|..|...#.
+..|#.....#
+#.#|||#|#|
+...#.||...
+.|....|...
+||...#|.#|
+|.||||..|.
+...#.|..|.
+";
+
+    /// States after each of the first 10 minutes, from the puzzle statement.
+    const MINUTES: [&str; 10] = [
+        "\
+.......##.
+......|###
+.|..|...#.
+..|#||...#
+..##||.|#|
+...#||||..
+||...|||..
+|||||.||.|
+||||||||||
+....||..|.
+",
+        "\
+.......#..
+......|#..
+.|.|||....
+..##|||..#
+..###|||#|
+...#|||||.
+|||||||||.
+||||||||||
+||||||||||
+.|||||||||
+",
+        "\
+.......#..
+....|||#..
+.|.||||...
+..###|||.#
+...##|||#|
+.||##|||||
+||||||||||
+||||||||||
+||||||||||
+||||||||||
+",
+        "\
+.....|.#..
+...||||#..
+.|.#||||..
+..###||||#
+...###||#|
+|||##|||||
+||||||||||
+||||||||||
+||||||||||
+||||||||||
+",
+        "\
+....|||#..
+...||||#..
+.|.##||||.
+..####|||#
+.|.###||#|
+|||###||||
+||||||||||
+||||||||||
+||||||||||
+||||||||||
+",
+        "\
+...||||#..
+...||||#..
+.|.###|||.
+..#.##|||#
+|||#.##|#|
+|||###||||
+||||#|||||
+||||||||||
+||||||||||
+||||||||||
+",
+        "\
+...||||#..
+..||#|##..
+.|.####||.
+||#..##||#
+||##.##|#|
+|||####|||
+|||###||||
+||||||||||
+||||||||||
+||||||||||
+",
+        "\
+..||||##..
+..|#####..
+|||#####|.
+||#...##|#
+||##..###|
+||##.###||
+|||####|||
+||||#|||||
+||||||||||
+||||||||||
+",
+        "\
+..||###...
+.||#####..
+||##...##.
+||#....###
+|##....##|
+||##..###|
+||######||
+|||###||||
+||||||||||
+||||||||||
+",
+        "\
+.||##.....
+||###.....
+||##......
+|##.....##
+|##.....##
+|##....##|
+||##.####|
+||#####|||
+||||#|||||
+||||||||||
+",
+    ];
+
+    #[test]
+    fn example_minutes() {
+        let mut state = load_initial_state(EXAMPLE).unwrap();
+        assert_eq!(state.to_string(), EXAMPLE);
+        for (i, expected) in MINUTES.iter().enumerate() {
+            state = get_new_state(&state);
+            assert_eq!(state.to_string(), *expected, "after {} minutes", i + 1);
+        }
+        assert_eq!(calculate_resource_index(&state), 37 * 31);
+    }
+
+    #[test]
+    fn example() {
+        assert_eq!(solve(EXAMPLE), Ok(1147));
+    }
+}
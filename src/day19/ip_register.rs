@@ -0,0 +1,125 @@
+use elfcode::Instruction;
+use elfcode::Machine;
+use elfcode::Program;
+use elfcode::VmError;
+
+use crate::parse;
+use crate::parse::LineParser;
+use crate::parse::ParseError;
+
+const NUM_OF_REGISTERS: usize = 6;
+
+/// Columns of the whitespace-separated words in the rest of the line.
+fn word_columns(line: &mut LineParser) -> Vec<usize> {
+    let mut columns = Vec::new();
+    line.skip_whitespace();
+    while !line.rest().is_empty() {
+        columns.push(line.column());
+        line.take_while(|c| !c.is_whitespace());
+        line.skip_whitespace();
+    }
+    return columns;
+}
+
+fn register_error(line: &LineParser, column: usize, register: usize) -> ParseError {
+    return line.error_at(column, format!("register below {}", NUM_OF_REGISTERS), register.to_string());
+}
+
+fn parse_program(input: &str) -> Result<Program, ParseError> {
+    let mut ip_register = None;
+    let mut instructions = Vec::new();
+    for mut line in parse::lines(input) {
+        if line.is_blank() {
+            continue;
+        }
+        line.skip_whitespace();
+        if line.rest().starts_with("#ip") {
+            line.literal("#ip")?;
+            line.skip_whitespace();
+            let column = line.column();
+            let register = line.integer()?;
+            if register >= NUM_OF_REGISTERS {
+                return Err(register_error(&line, column, register));
+            }
+            ip_register = Some(register);
+            line.end()?;
+        } else {
+            let column = line.column();
+            let instruction: Instruction = line.rest().parse()
+                .map_err(|err| line.error_at(column, "instruction", format!("{}", err)))?;
+            // Words after the op code are the operands a, b and c.
+            let columns = word_columns(&mut line);
+            let operands = [
+                (instruction.op_code.is_a_register(), instruction.a),
+                (instruction.op_code.is_b_register(), instruction.b),
+                (true, instruction.c),
+            ];
+            for (i, (is_register, value)) in operands.iter().enumerate() {
+                if *is_register && *value >= NUM_OF_REGISTERS {
+                    return Err(register_error(&line, columns[i + 1], *value));
+                }
+            }
+            instructions.push(instruction);
+        }
+    }
+    if ip_register.is_none() {
+        return Err(ParseError::new(1, 1, "\"#ip\" directive", "none"));
+    }
+    return Ok(Program { ip_register, instructions });
+}
+
+/// Register 0 after the program halts, or `None` if it overflows a
+/// register.
+pub fn solve(input: &str) -> Result<Option<usize>, ParseError> {
+    let program = parse_program(input)?;
+
+    let mut machine = Machine::new(NUM_OF_REGISTERS);
+    match machine.run(&program) {
+        Ok(_) => return Ok(Some(machine.registers[0])),
+        Err(VmError::Overflow(_)) => return Ok(None),
+        Err(err) => panic!("registers are checked by parse_program: {}", err),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+#ip 0
+seti 5 0 1
+seti 6 0 2
+addi 0 1 0
+addr 1 2 3
+setr 1 0 0
+seti 8 0 4
+seti 9 0 5
+";
+
+    #[test]
+    fn example_final_registers() {
+        let program = parse_program(EXAMPLE).unwrap();
+        let mut machine = Machine::new(NUM_OF_REGISTERS);
+        assert_eq!(machine.run(&program), Ok(5));
+        assert_eq!(machine.registers.as_slice(), &[6, 5, 6, 0, 0, 9]);
+    }
+
+    #[test]
+    fn example() {
+        assert_eq!(solve(EXAMPLE), Ok(Some(6)));
+    }
+
+    #[test]
+    fn invalid_registers() {
+        assert_eq!(solve("#ip 9\nseti 5 0 1\n"), Err(ParseError::new(1, 5, "register below 6", "9")));
+        assert_eq!(solve("#ip 0\nseti 5 0 9\n"), Err(ParseError::new(2, 10, "register below 6", "9")));
+        assert_eq!(solve("#ip 0\naddr 1 6 2\n"), Err(ParseError::new(2, 8, "register below 6", "6")));
+        // Immediate operands may be any value.
+        assert_eq!(solve("#ip 0\nseti 99 0 1\n"), Ok(Some(0)));
+    }
+
+    #[test]
+    fn overflow() {
+        assert_eq!(solve("#ip 5\nseti 18446744073709551615 0 1\naddi 1 1 0\n"), Ok(None));
+    }
+}
@@ -1,26 +1,14 @@
 use std::cmp;
 use std::fmt;
-use std::io;
 
 use std::collections::HashMap;
 use std::collections::HashSet;
 use std::collections::VecDeque;
-use std::io::Read;
 
-type Coord = i32;
-type Point = (Coord, Coord);
-
-trait PointOps {
-    fn add(&self, other: &Point) -> Point;
-}
-impl PointOps for Point {
-
-    fn add(&self, other: &Point) -> Point {
-        let (sx, sy) = self;
-        let (ox, oy) = other;
-        return (*sx + *ox, *sy + *oy);
-    }
-}
+use crate::geometry::BoundingBox;
+use crate::geometry::Point2 as Point;
+use crate::parse;
+use crate::parse::ParseError;
 
 #[derive(Eq, PartialEq, Debug, Hash, Clone)]
 enum Direction {
@@ -30,21 +18,21 @@ enum Direction {
     E,
 }
 impl Direction {
-    fn from_char(c: char) -> Direction {
+    fn from_char(c: char) -> Option<Direction> {
         match c {
-            'N' => Direction::N,
-            'W' => Direction::W,
-            'S' => Direction::S,
-            'E' => Direction::E,
-            _ => panic!("direction not recognized: {}", c),
+            'N' => Some(Direction::N),
+            'W' => Some(Direction::W),
+            'S' => Some(Direction::S),
+            'E' => Some(Direction::E),
+            _ => None,
         }
     }
     fn to_delta_point(&self) -> Point {
         match self {
-            Direction::N => (0, -1),
-            Direction::W => (-1, 0),
-            Direction::S => (0, 1),
-            Direction::E => (1, 0),
+            Direction::N => Point::new([0, -1]),
+            Direction::W => Point::new([-1, 0]),
+            Direction::S => Point::new([0, 1]),
+            Direction::E => Point::new([1, 0]),
         }
     }
     fn invert(&self) -> Direction {
@@ -83,21 +71,18 @@ impl Map {
             default_field: Field::new(),
         }
     }
-    fn lower_bound(&self) -> Point {
-        let x = self.fields.keys().fold(0, |m, (elx, _)| cmp::min(m, *elx));
-        let y = self.fields.keys().fold(0, |m, (_, ely)| cmp::min(m, *ely));
-        (x, y)
-    }
-    fn upper_bound(&self) -> Point {
-        let x = self.fields.keys().fold(0, |m, (elx, _)| cmp::max(m, *elx));
-        let y = self.fields.keys().fold(0, |m, (_, ely)| cmp::max(m, *ely));
-        (x, y)
+    fn bounds(&self) -> BoundingBox<2> {
+        let mut bounds = BoundingBox::from_point(Point::origin());
+        for p in self.fields.keys() {
+            bounds.expand(p);
+        }
+        return bounds;
     }
     fn get_field(&self, point: &Point) -> &Field {
         self.fields.get(point).unwrap_or(&self.default_field)
     }
     fn move_to(&mut self, point: &Point, direction: Direction) {
-        let next_point = point.add(&direction.to_delta_point());
+        let next_point = *point + direction.to_delta_point();
         {
             let field = self.fields.entry(*point)
                 .or_insert(Field::new());
@@ -113,8 +98,9 @@ impl Map {
 
 impl fmt::Debug for Map {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        let (minx, miny) = self.lower_bound();
-        let (maxx, maxy) = self.upper_bound();
+        let bounds = self.bounds();
+        let (minx, miny) = (bounds.min.x(), bounds.min.y());
+        let (maxx, maxy) = (bounds.max.x(), bounds.max.y());
 
         let mut buffer = String::new();
 
@@ -128,7 +114,7 @@ impl fmt::Debug for Map {
             }
             buffer.push('#');
             for x in minx..=maxx {
-                let field = self.get_field(&(x, y));
+                let field = self.get_field(&Point::new([x, y]));
                 buffer.push('.');
                 if field.has_door(Direction::E) {
                     buffer.push('|');
@@ -139,7 +125,7 @@ impl fmt::Debug for Map {
             buffer.push('\n');
             buffer.push('#');
             for x in minx..=maxx {
-                let field = self.get_field(&(x, y));
+                let field = self.get_field(&Point::new([x, y]));
                 if field.has_door(Direction::S) {
                     buffer.push('-');
                 } else {
@@ -163,49 +149,51 @@ impl StackExtra<Point> for Vec<Point> {
     }
 }
 
-fn build_map(direction_regex: &String) -> Map {
+fn build_map(direction_regex: &str) -> Result<Map, ParseError> {
     let mut map = Map::new();
     let mut stack: Vec<Point> = Vec::new();
-    let mut point: Point = (0, 0);
-    for c in direction_regex.chars() {
-        match c {
-            '^' => {},
-            '$' => {},
-            '(' => {
-                stack.push(point.clone());
-            },
-            '|' => {
-                point = stack.top().unwrap();
-            }
-            ')' => {
-                stack.pop();
-            }
-            '\n' => {},
-            _ => {
-                let direction = Direction::from_char(c);
-                let delta = direction.to_delta_point();
-                map.move_to(&point, direction);
-                point = point.add(&delta);
+    let mut point = Point::origin();
+    for line in parse::lines(direction_regex) {
+        for (i, c) in line.text().chars().enumerate() {
+            let unexpected = |expected: &str| line.error_at(i + 1, expected, format!("{:?}", c));
+            match c {
+                '^' => {},
+                '$' => {},
+                '(' => {
+                    stack.push(point);
+                },
+                '|' => {
+                    point = stack.top().ok_or_else(|| unexpected("direction or '('"))?;
+                }
+                ')' => {
+                    stack.pop().ok_or_else(|| unexpected("direction or '('"))?;
+                }
+                _ => {
+                    let direction = Direction::from_char(c).ok_or_else(|| unexpected("direction"))?;
+                    let delta = direction.to_delta_point();
+                    map.move_to(&point, direction);
+                    point += delta;
+                }
             }
         }
     }
-    return map;
+    return Ok(map);
 }
 
 fn get_furthest_room(map: &Map) -> usize {
     let mut distances: HashMap<Point, usize> = HashMap::new();
     let mut q = VecDeque::new();
 
-    q.push_back(((0, 0), 0));
+    q.push_back((Point::origin(), 0));
 
     while !q.is_empty() {
         let (p, dist) = q.pop_front().unwrap();
         let field = map.get_field(&p);
         match distances.get(&p).cloned() {
             None => {
-                distances.insert(p.clone(), dist);
+                distances.insert(p, dist);
                 for direction in &field.doors {
-                    let next_p = p.add(&direction.to_delta_point());
+                    let next_p = p + direction.to_delta_point();
                     q.push_back((next_p, dist + 1));
                 }
             },
@@ -218,13 +206,41 @@ fn get_furthest_room(map: &Map) -> usize {
     distances.values().fold(0, |acc, d| cmp::max(acc, *d))
 }
 
-fn main() {
-    let mut regex = String::new();
-    let stdin = io::stdin();
-    stdin.lock().read_to_string(&mut regex)
-        .expect("reading stdin failed");
-    let map = build_map(&regex);
-    println!("{:?}", map);
-    println!("{}", get_furthest_room(&map));
+pub fn solve(input: &str) -> Result<usize, ParseError> {
+    let map = build_map(input)?;
+    return Ok(get_furthest_room(&map));
 }
 
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example_map() {
+        let map = build_map("^ENWWW(NEEE|SSE(EE|N))$").unwrap();
+        // Same as in the puzzle statement, with the starting room `X` shown as `.`.
+        let expected = "\
+#########
+#.|.|.|.#
+#-#######
+#.|.|.|.#
+#-#####-#
+#.#.#.|.#
+#-#-#####
+#.|.|.|.#
+#########
+
+";
+        assert_eq!(format!("{:?}", map), expected);
+    }
+
+    #[test]
+    fn examples() {
+        assert_eq!(solve("^WNE$"), Ok(3));
+        assert_eq!(solve("^ENWWW(NEEE|SSE(EE|N))$"), Ok(10));
+        assert_eq!(solve("^ENNWSWW(NEWS|)SSSEEN(WNSE|)EE(SWEN|)NNN$"), Ok(18));
+        assert_eq!(solve("^ESSWWN(E|NNENN(EESS(WNSE|)SSS|WWWSSSSE(SW|NNNE)))$"), Ok(23));
+        assert_eq!(solve("^WSSEESWWWNW(S|NENNEEEENN(ESSSSW(NWSW|SSEN)|WSWWN(E|WWS(E|SS))))$"), Ok(31));
+    }
+}
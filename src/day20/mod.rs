@@ -0,0 +1 @@
+pub mod largest_num_of_doors;
@@ -0,0 +1 @@
+pub mod total_risk_level;
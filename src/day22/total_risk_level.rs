@@ -0,0 +1,165 @@
+use std::fmt;
+
+use crate::diagnostics::Diagnostics;
+use crate::grid::Grid;
+use crate::grid::ToChar;
+use crate::parse;
+use crate::parse::ParseError;
+
+type Point = (usize, usize);
+
+#[derive(Eq, PartialEq, Clone)]
+enum RegionType {
+    Rocky,
+    Narrow,
+    Wet,
+}
+impl ToChar for RegionType {
+    fn to_char(&self) -> char {
+        match self {
+            RegionType::Rocky => '.',
+            RegionType::Narrow => '|',
+            RegionType::Wet => '=',
+        }
+    }
+}
+impl fmt::Debug for RegionType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.to_char())
+    }
+}
+
+fn read_input(input: &str) -> Result<(usize, Point), ParseError> {
+    let mut line_iter = parse::lines(input);
+
+    let mut first_line = match line_iter.next() {
+        Some(line) => line,
+        None => return Err(ParseError::new(1, 1, "\"depth: \"", "end of input")),
+    };
+    first_line.literal("depth:")?;
+    let depth: usize = first_line.integer()?;
+    first_line.end()?;
+
+    let mut second_line = match line_iter.next() {
+        Some(line) => line,
+        None => return Err(ParseError::new(2, 1, "\"target: \"", "end of input")),
+    };
+    second_line.literal("target:")?;
+    let x = second_line.integer()?;
+    second_line.literal(",")?;
+    let y = second_line.integer()?;
+    second_line.end()?;
+
+    return Ok((depth, (x, y)));
+}
+
+const MODULO: usize = 20183;
+
+fn generate_geologic_index_matrix_modulo(target: &Point, depth: usize) -> Grid<usize> {
+    let width = target.0 + 1;
+    let height = target.1 + 1;
+    let mut m = Grid::new(width, height, 0);
+
+    for x in 1..width {
+        m[(x, 0)] = (x * 16807) % MODULO;
+    }
+
+    for y in 1..height {
+        m[(0, y)] = (y * 48271) % MODULO;
+    }
+
+    for y in 1..height {
+        for x in 1..width {
+            if x == target.0 && y == target.1 {
+                continue;
+            }
+            let el_up = geologic_index_to_erosion_level(m[(x, y - 1)], depth);
+            let el_left = geologic_index_to_erosion_level(m[(x - 1, y)], depth);
+            m[(x, y)] = (el_up * el_left) % MODULO;
+        }
+    }
+
+    return m;
+}
+
+fn generate_map(target: &Point, depth: usize) -> Grid<RegionType> {
+    let gi = generate_geologic_index_matrix_modulo(target, depth);
+    return gi.map(|index| match geologic_index_to_erosion_level(*index, depth) % 3 {
+        0 => RegionType::Rocky,
+        1 => RegionType::Wet,
+        2 => RegionType::Narrow,
+        _ => panic!("should not happen"),
+    });
+}
+
+fn geologic_index_to_erosion_level(index: usize, depth: usize) -> usize {
+    (index + depth) % MODULO
+}
+
+fn calculate_risk(map: &Grid<RegionType>) -> usize {
+    let mut counter = 0;
+    for cell in map.iter() {
+        counter += match cell {
+            RegionType::Rocky => 0,
+            RegionType::Wet => 1,
+            RegionType::Narrow => 2,
+        }
+    }
+    return counter;
+}
+
+pub fn solve(input: &str) -> Result<usize, ParseError> {
+    return solve_with(input, &mut Diagnostics::new());
+}
+
+/// Like `solve`, also reporting the scan parameters and tracing the map.
+pub fn solve_with(input: &str, diagnostics: &mut Diagnostics) -> Result<usize, ParseError> {
+    let (depth, target) = read_input(input)?;
+    diagnostics.set("depth", depth);
+    diagnostics.set("target", target);
+    let map = generate_map(&target, depth);
+    diagnostics.trace(|| map.to_string().trim_end().to_string());
+    let risk = calculate_risk(&map);
+    return Ok(risk);
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "depth: 510\ntarget: 10,10\n";
+
+    #[test]
+    fn example_regions() {
+        let (depth, target) = read_input(EXAMPLE).unwrap();
+        let gi = generate_geologic_index_matrix_modulo(&target, depth);
+        assert_eq!(geologic_index_to_erosion_level(gi[(0, 0)], depth), 510);
+        assert_eq!(geologic_index_to_erosion_level(gi[(1, 0)], depth), 17317);
+        assert_eq!(geologic_index_to_erosion_level(gi[(0, 1)], depth), 8415);
+        assert_eq!(geologic_index_to_erosion_level(gi[(1, 1)], depth), 1805);
+        assert_eq!(geologic_index_to_erosion_level(gi[(10, 10)], depth), 510);
+
+        // Top-left part of the map from the puzzle statement, with the mouth
+        // `M` and the target `T` shown as the rocky regions they are.
+        let expected = "\
+.=.|=.|.|=.
+.|=|=|||..|
+.==|....||=
+=.|....|.==
+=|..==...=.
+=||.=.=||=|
+|.=.===|||.
+|..==||=.|=
+.=..===..=|
+.======|||=
+.===|=|===.
+";
+        assert_eq!(generate_map(&target, depth).to_string(), expected);
+    }
+
+    #[test]
+    fn example() {
+        assert_eq!(solve(EXAMPLE), Ok(114));
+    }
+}
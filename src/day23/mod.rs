@@ -0,0 +1 @@
+pub mod nanobots_in_range;
@@ -0,0 +1,77 @@
+use crate::geometry::Point3;
+use crate::parse;
+use crate::parse::ParseError;
+
+#[derive(Debug)]
+struct NanoBot {
+    position: Point3,
+    radius: usize,
+}
+
+fn read_input(input: &str) -> Result<Vec<NanoBot>, ParseError> {
+    let mut bots = Vec::new();
+    for mut line in parse::lines(input) {
+        line.skip_whitespace();
+        line.literal("pos=<")?;
+        let x = line.integer()?;
+        line.literal(",")?;
+        let y = line.integer()?;
+        line.literal(",")?;
+        let z = line.integer()?;
+        line.literal(">,")?;
+        line.skip_whitespace();
+        line.literal("r=")?;
+        let radius = line.integer()?;
+        line.end()?;
+        bots.push(NanoBot {position: Point3::new([x, y, z]), radius});
+    }
+    return Ok(bots);
+}
+
+pub fn solve(input: &str) -> Result<usize, ParseError> {
+    let bots = read_input(input)?;
+
+    let mut largest_radius_bot_opt: Option<&NanoBot> = None;
+
+    for bot in &bots {
+        largest_radius_bot_opt = match largest_radius_bot_opt {
+            None => Some(bot),
+            Some(b) => if bot.radius > b.radius { Some(bot) } else { Some(b) }
+        }
+    }
+
+    let largest_radius_bot = match largest_radius_bot_opt {
+        Some(bot) => bot,
+        None => return Err(ParseError::new(1, 1, "nanobot", "end of input")),
+    };
+
+    let mut counter = 0;
+
+    for bot in &bots {
+        if largest_radius_bot.position.manhattan(&bot.position) as usize <= largest_radius_bot.radius {
+            counter += 1;
+        }
+    }
+    return Ok(counter);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example() {
+        let input = "\
+pos=<0,0,0>, r=4
+pos=<1,0,0>, r=1
+pos=<4,0,0>, r=3
+pos=<0,2,0>, r=1
+pos=<0,5,0>, r=3
+pos=<0,0,3>, r=1
+pos=<1,1,1>, r=1
+pos=<1,1,2>, r=1
+pos=<1,3,1>, r=1
+";
+        assert_eq!(solve(input), Ok(7));
+    }
+}
@@ -0,0 +1,352 @@
+use std::cmp;
+
+use std::cmp::Ordering;
+use std::collections::HashMap;
+use std::collections::HashSet;
+
+use crate::diagnostics::Diagnostics;
+use crate::parse;
+use crate::parse::LineParser;
+use crate::parse::ParseError;
+
+type DamagePoints = u32;
+type GroupId = usize;
+
+#[derive(Eq, PartialEq, Hash, Debug, Clone)]
+enum DamageType {
+    Slashing,
+    Cold,
+    Radiation,
+    Bludgeoning,
+    Fire,
+}
+impl DamageType {
+    fn from_name(s: &str) -> Option<DamageType> {
+        match s {
+            "slashing" => Some(DamageType::Slashing),
+            "cold" => Some(DamageType::Cold),
+            "radiation" => Some(DamageType::Radiation),
+            "bludgeoning" => Some(DamageType::Bludgeoning),
+            "fire" => Some(DamageType::Fire),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Eq, PartialEq, Debug, Clone, Hash)]
+enum GroupType {
+    ImmuneSystem,
+    Infection
+}
+
+#[derive(Eq, PartialEq, Clone, Debug)]
+struct Group {
+    group_type: GroupType,
+    num_of_units: usize,
+    hit_points: DamagePoints,
+    weak_to: HashSet<DamageType>,
+    immune_to: HashSet<DamageType>,
+    damage_type: DamageType,
+    damage: DamagePoints,
+    initiative: usize,
+}
+impl Group {
+    fn get_effective_power(&self) -> DamagePoints {
+        self.num_of_units as DamagePoints * self.damage
+    }
+    fn deal_damage_by(&mut self, group: &Self) {
+        let damage = group.estimate_damage(self);
+        let num_of_killed_units = (damage / self.hit_points) as usize;
+        if num_of_killed_units < self.num_of_units {
+            self.num_of_units -= num_of_killed_units;
+        } else {
+            self.num_of_units = 0;
+        }
+    }
+    fn estimate_damage(&self, group: &Self) -> DamagePoints {
+        let effective_power = self.get_effective_power();
+        if group.immune_to.contains(&self.damage_type) {
+            return 0;
+        }
+        if group.weak_to.contains(&self.damage_type) {
+            return 2 * effective_power;
+        }
+        return effective_power;
+    }
+}
+impl cmp::Ord for Group {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.get_effective_power().cmp(&other.get_effective_power()).reverse()
+            .then(self.initiative.cmp(&other.initiative).reverse())
+    }
+}
+impl cmp::PartialOrd for Group {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+fn parse_damage_type(line: &mut LineParser) -> Result<DamageType, ParseError> {
+    line.skip_whitespace();
+    let start = line.clone();
+    let name = line.take_while(|c| c.is_ascii_alphabetic());
+    return DamageType::from_name(name).ok_or_else(|| start.error("damage type"));
+}
+
+/// Parses the weaknesses and immunities, e.g. `(immune to fire; weak to cold, slashing)`.
+fn parse_weak_immune(line: &mut LineParser, weak_to: &mut HashSet<DamageType>, immune_to: &mut HashSet<DamageType>) -> Result<(), ParseError> {
+    line.literal("(")?;
+    loop {
+        line.skip_whitespace();
+        let start = line.clone();
+        let damage_types = match line.take_while(|c| c.is_ascii_alphabetic()) {
+            "weak" => &mut *weak_to,
+            "immune" => &mut *immune_to,
+            _ => return Err(start.error("\"weak\" or \"immune\"")),
+        };
+        line.literal(" to")?;
+        loop {
+            damage_types.insert(parse_damage_type(line)?);
+            if line.literal(",").is_err() {
+                break;
+            }
+        }
+        if line.literal(";").is_err() {
+            break;
+        }
+    }
+    return line.literal(")");
+}
+
+/// Parses a group description, e.g. `18 units each with 729 hit points
+/// (weak to fire) with an attack that does 8 radiation damage at initiative 10`.
+fn parse_group(line: &mut LineParser, group_type: GroupType) -> Result<Group, ParseError> {
+    let num_of_units: usize = line.integer()?;
+    line.literal(" units each with ")?;
+    let hit_points: DamagePoints = line.integer()?;
+    line.literal(" hit points")?;
+    line.skip_whitespace();
+
+    let mut weak_to: HashSet<DamageType> = HashSet::new();
+    let mut immune_to: HashSet<DamageType> = HashSet::new();
+    if line.rest().starts_with('(') {
+        parse_weak_immune(line, &mut weak_to, &mut immune_to)?;
+        line.skip_whitespace();
+    }
+
+    line.literal("with an attack that does ")?;
+    let damage: DamagePoints = line.integer()?;
+    let damage_type = parse_damage_type(line)?;
+    line.literal(" damage at initiative ")?;
+    let initiative: usize = line.integer()?;
+    line.end()?;
+
+    return Ok(Group {
+        group_type,
+        num_of_units,
+        hit_points,
+        weak_to,
+        immune_to,
+        damage,
+        damage_type,
+        initiative,
+    });
+}
+
+fn argmax<T: Clone, S: cmp::Ord>(vec: &Vec<T>, f: impl Fn(&T) -> S) -> Option<T> {
+    let mut best_el: Option<&T> = None;
+
+    for el in vec {
+        let val = f(el);
+        match best_el.map(&f) {
+            None => {
+                best_el = Some(el);
+            },
+            Some(bv) => {
+                match bv.cmp(&val) {
+                    Ordering::Less => {
+                        best_el = Some(el);
+                    },
+                    _ => {},
+                }
+            },
+        }
+    }
+
+    return best_el.cloned();
+}
+
+fn read_input(input: &str) -> Result<Vec<Group>, ParseError> {
+    let mut group_type = GroupType::ImmuneSystem;
+    let mut groups = Vec::new();
+    for mut line in parse::lines(input) {
+        let l = line.text().trim();
+        if l.is_empty() {
+            continue;
+        }
+        else if l == "Immune System:" {
+            group_type = GroupType::ImmuneSystem;
+        }
+        else if l == "Infection:" {
+            group_type = GroupType::Infection;
+        }
+        else {
+            groups.push(parse_group(&mut line, group_type.clone())?);
+        }
+    }
+    return Ok(groups);
+}
+
+fn get_group_types(groups_map: &HashMap<GroupId, Group>) -> HashSet<GroupType> {
+    let mut group_types: HashSet<GroupType> = HashSet::new();
+    for g in groups_map.values() {
+        group_types.insert(g.group_type.clone());
+    }
+    return group_types;
+}
+
+fn trace_groups(diagnostics: &mut Diagnostics, groups_map: &HashMap<GroupId, Group>) {
+    let mut group_ids: Vec<&GroupId> = groups_map.keys().collect();
+    group_ids.sort();
+    for gid in group_ids {
+        diagnostics.trace(|| format!("Group {}: {:?}", gid, groups_map[gid]));
+    }
+}
+
+pub fn solve(input: &str) -> Result<usize, ParseError> {
+    return solve_with(input, &mut Diagnostics::new());
+}
+
+/// Like `solve`, also reporting the winner and tracing the battle log.
+pub fn solve_with(input: &str, diagnostics: &mut Diagnostics) -> Result<usize, ParseError> {
+    let initial_groups = read_input(input)?;
+    let mut groups_map: HashMap<GroupId, Group> = initial_groups.into_iter().enumerate().collect();
+    let mut num_of_rounds = 0;
+    while get_group_types(&groups_map).len() > 1 {
+        num_of_rounds += 1;
+        diagnostics.trace(|| "-- Next turn --".to_string());
+        trace_groups(diagnostics, &groups_map);
+        let mut group_items: Vec<(GroupId, Group)> = Vec::new();
+        for (k,v) in &groups_map {
+            group_items.push((*k, v.clone()));
+        }
+
+        group_items.sort_by(|(_, a), (_, b)| a.get_effective_power().cmp(&b.get_effective_power()).reverse().then(a.initiative.cmp(&b.initiative).reverse()));
+
+        let mut selected_ids_map: HashMap<GroupId, GroupId> = HashMap::new();
+        let mut attacked_ids: HashSet<GroupId> = HashSet::new();
+        let mut attack_sequence: Vec<(GroupId, GroupId)> = Vec::new();
+
+        for (group_id, group) in &group_items {
+            let selection_opt = argmax(
+                &group_items.iter()
+                    .filter(|(_, g)| g.group_type != group.group_type)
+                    .filter(|(i, _)| !attacked_ids.contains(i))
+                    .collect::<Vec<&(GroupId, Group)>>(),
+                |(_, g)| group.estimate_damage(g));
+
+            if selection_opt.is_none() {
+                continue;
+            }
+
+            let (sel_group_id, _) = selection_opt.unwrap();
+
+            selected_ids_map.insert(*group_id, *sel_group_id);
+            attacked_ids.insert(*sel_group_id);
+        }
+
+        group_items.sort_by(|(_, a), (_, b)| a.initiative.cmp(&b.initiative).reverse());
+
+        for (group_id, _) in &group_items {
+            let selected_group_id_opt = selected_ids_map.get(group_id);
+            if selected_group_id_opt.is_none() {
+                continue;
+            }
+            let selected_group_id: GroupId = *selected_group_id_opt.unwrap();
+            attack_sequence.push((*group_id, selected_group_id));
+        }
+
+        for (group_id, selected_group_id) in attack_sequence {
+            let damaging_group: Group = groups_map.get(&group_id).unwrap().clone();
+            let group: &mut Group = groups_map.get_mut(&selected_group_id).unwrap();
+            let num_of_units_before = group.num_of_units;
+            group.deal_damage_by(&damaging_group);
+            let num_of_killed_units = num_of_units_before - group.num_of_units;
+            diagnostics.trace(|| format!("Group {} attacks group {}, killing {} units", group_id, selected_group_id, num_of_killed_units));
+        }
+
+        let eliminated_ids: Vec<_> = groups_map.iter().filter(|(_,g)| g.num_of_units == 0).map(|(gid,_)| gid).cloned().collect();
+
+        for id in eliminated_ids {
+            diagnostics.trace(|| format!("Group {} eliminated", id));
+            groups_map.remove(&id);
+        }
+    }
+
+    diagnostics.trace(|| "-- finish --".to_string());
+    trace_groups(diagnostics, &groups_map);
+    diagnostics.set("rounds", num_of_rounds);
+    match groups_map.values().next() {
+        Some(group) => diagnostics.set("winner", format!("{:?}", group.group_type)),
+        None => {},
+    }
+
+    let num_of_winning_units = groups_map.values().map(|g| g.num_of_units).sum::<usize>();
+
+    return Ok(num_of_winning_units);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+Immune System:
+17 units each with 5390 hit points (weak to radiation, bludgeoning) with an attack that does 4507 fire damage at initiative 2
+989 units each with 1274 hit points (immune to fire; weak to bludgeoning, slashing) with an attack that does 25 slashing damage at initiative 3
+
+Infection:
+801 units each with 4706 hit points (weak to radiation) with an attack that does 116 bludgeoning damage at initiative 1
+4485 units each with 2961 hit points (immune to radiation; weak to fire, cold) with an attack that does 12 slashing damage at initiative 4
+";
+
+    #[test]
+    fn example_groups() {
+        let groups = read_input(EXAMPLE).unwrap();
+        assert_eq!(groups.len(), 4);
+        assert_eq!(groups[0].group_type, GroupType::ImmuneSystem);
+        assert_eq!(groups[0].get_effective_power(), 76619);
+        assert_eq!(groups[1].immune_to, [DamageType::Fire].iter().cloned().collect());
+        assert_eq!(groups[1].weak_to, [DamageType::Bludgeoning, DamageType::Slashing].iter().cloned().collect());
+        assert_eq!(groups[3].group_type, GroupType::Infection);
+        assert_eq!(groups[3].get_effective_power(), 53820);
+        // Damage estimates from the first fight of the statement.
+        assert_eq!(groups[2].estimate_damage(&groups[0]), 185832);
+        assert_eq!(groups[2].estimate_damage(&groups[1]), 185832);
+        assert_eq!(groups[3].estimate_damage(&groups[1]), 107640);
+        assert_eq!(groups[0].estimate_damage(&groups[3]), 153238);
+    }
+
+    #[test]
+    fn example() {
+        assert_eq!(solve(EXAMPLE), Ok(5216));
+    }
+
+    #[test]
+    fn example_battle_log() {
+        let mut diagnostics = Diagnostics::with_trace();
+        assert_eq!(solve_with(EXAMPLE, &mut diagnostics), Ok(5216));
+        assert_eq!(diagnostics.get("winner"), Some(&"Infection".to_string().into()));
+        // First fight of the statement, groups numbered in input order.
+        let attacks: Vec<&String> = diagnostics.trace_lines().iter()
+            .filter(|line| line.contains("attacks"))
+            .take(4)
+            .collect();
+        assert_eq!(attacks, vec![
+            "Group 3 attacks group 1, killing 84 units",
+            "Group 1 attacks group 2, killing 4 units",
+            "Group 0 attacks group 3, killing 51 units",
+            "Group 2 attacks group 0, killing 17 units",
+        ]);
+    }
+}
@@ -0,0 +1 @@
+pub mod immune_system_simulation;
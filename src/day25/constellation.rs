@@ -0,0 +1,146 @@
+use std::cmp;
+
+use std::collections::HashSet;
+
+use crate::geometry::Point4;
+use crate::parse;
+use crate::parse::ParseError;
+
+struct FindUnion {
+    parent_indexes: Vec<usize>,
+    depths: Vec<usize>,
+}
+impl FindUnion {
+    fn new(n: usize) -> Self {
+        let parent_indexes: Vec<usize> = (0..n).collect();
+        let depths = vec![0; n];
+        return FindUnion {
+            parent_indexes,
+            depths,
+        };
+    }
+
+    fn find_root_and_compress_path(&mut self, x: usize) -> usize {
+        if self.parent_indexes[x] == x {
+            return x;
+        }
+        let parent = self.parent_indexes[x];
+        let root = self.find_root_and_compress_path(parent);
+        self.parent_indexes[x] = root;
+        self.depths[x] = 0;
+        return root;
+    }
+
+    fn union(&mut self, x: usize, y: usize) {
+        let root_x = self.find_root_and_compress_path(x);
+        let root_y = self.find_root_and_compress_path(y);
+        if self.depths[root_x] > self.depths[root_y] {
+            // x is the root
+            self.parent_indexes[root_y] = root_x;
+            self.depths[root_x] = cmp::max(self.depths[root_x], self.depths[root_y] + 1);
+        } else {
+            // y is the root
+            self.parent_indexes[root_x] = root_y;
+            self.depths[root_y] = cmp::max(self.depths[root_x] + 1, self.depths[root_y]);
+        }
+    }
+}
+
+fn read_input(input: &str) -> Result<Vec<Point4>, ParseError> {
+    let mut points = Vec::new();
+    for mut line in parse::lines(input) {
+        if line.is_blank() {
+            continue;
+        }
+        let mut p = Point4::origin();
+        for i in 0..4 {
+            if i > 0 {
+                line.literal(",")?;
+            }
+            p[i] = line.integer()?;
+        }
+        line.end()?;
+        points.push(p);
+    }
+    return Ok(points);
+}
+
+pub fn solve(input: &str) -> Result<usize, ParseError> {
+    let points = read_input(input)?;
+    let n = points.len();
+    let mut find_union = FindUnion::new(n);
+    for i in 0..n {
+        for j in (i + 1)..n {
+            if points[i].manhattan(&points[j]) <= 3 {
+                find_union.union(i, j);
+            }
+        }
+    }
+    let mut roots: HashSet<usize> = HashSet::new();
+    for i in 0..n {
+        roots.insert(find_union.find_root_and_compress_path(i));
+    }
+    return Ok(roots.len());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn examples() {
+        let input = "\
+ 0,0,0,0
+ 3,0,0,0
+ 0,3,0,0
+ 0,0,3,0
+ 0,0,0,3
+ 0,0,0,6
+ 9,0,0,0
+12,0,0,0
+";
+        assert_eq!(solve(input), Ok(2));
+
+        let input = "\
+-1,2,2,0
+0,0,2,-2
+0,0,0,-2
+-1,2,0,0
+-2,-2,-2,2
+3,0,2,-1
+-1,3,2,2
+-1,0,-1,0
+0,2,1,-2
+3,0,0,0
+";
+        assert_eq!(solve(input), Ok(4));
+
+        let input = "\
+1,-1,0,1
+2,0,-1,0
+3,2,-1,0
+0,0,3,1
+0,0,-1,-1
+2,3,-2,0
+-2,2,0,0
+2,-2,0,-1
+1,-1,0,-1
+3,2,0,2
+";
+        assert_eq!(solve(input), Ok(3));
+
+        let input = "\
+1,-1,-1,-2
+-2,-2,0,1
+0,2,1,3
+-2,3,-2,1
+0,2,3,-2
+-1,-1,1,-2
+0,-2,-1,0
+-2,2,3,-1
+1,2,2,0
+-1,-2,0,-2
+";
+        assert_eq!(solve(input), Ok(8));
+    }
+}
@@ -0,0 +1,51 @@
+//! Side information reported by the solvers next to the answer.
+
+use crate::Answer;
+
+/// Named values and (optionally) trace lines collected while solving.
+///
+/// Trace lines are only built when tracing is enabled, so solvers can log
+/// freely without slowing down the normal runs.
+#[derive(Clone, Debug, Default)]
+pub struct Diagnostics {
+    values: Vec<(String, Answer)>,
+    trace: Option<Vec<String>>,
+}
+impl Diagnostics {
+    pub fn new() -> Diagnostics {
+        Diagnostics { values: Vec::new(), trace: None }
+    }
+    pub fn with_trace() -> Diagnostics {
+        Diagnostics { values: Vec::new(), trace: Some(Vec::new()) }
+    }
+    /// Sets the value under given key, replacing the previous one.
+    pub fn set<T: Into<Answer>>(&mut self, key: &str, value: T) {
+        let value = value.into();
+        match self.values.iter_mut().find(|(k, _)| k == key) {
+            Some(entry) => entry.1 = value,
+            None => self.values.push((key.to_string(), value)),
+        }
+    }
+    pub fn get(&self, key: &str) -> Option<&Answer> {
+        return self.values.iter().find(|(k, _)| k == key).map(|(_, v)| v);
+    }
+    pub fn values(&self) -> &[(String, Answer)] {
+        return &self.values;
+    }
+    pub fn is_tracing(&self) -> bool {
+        return self.trace.is_some();
+    }
+    /// Appends the line returned by `f` if tracing is enabled.
+    pub fn trace(&mut self, f: impl FnOnce() -> String) {
+        match &mut self.trace {
+            Some(lines) => lines.push(f()),
+            None => {},
+        }
+    }
+    pub fn trace_lines(&self) -> &[String] {
+        match &self.trace {
+            Some(lines) => return lines,
+            None => return &[],
+        }
+    }
+}
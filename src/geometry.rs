@@ -0,0 +1,390 @@
+use std::cmp;
+use std::error;
+use std::fmt;
+use std::ops::Add;
+use std::ops::AddAssign;
+use std::ops::Div;
+use std::ops::Index;
+use std::ops::IndexMut;
+use std::ops::Mul;
+use std::ops::Neg;
+use std::ops::Sub;
+use std::ops::SubAssign;
+use std::str::FromStr;
+
+pub type Coord = i64;
+
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub enum ParsePointError {
+    InvalidFormat(String),
+    InvalidNumber(String),
+}
+impl fmt::Display for ParsePointError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ParsePointError::InvalidFormat(s) => write!(f, "invalid point format: {}", s),
+            ParsePointError::InvalidNumber(s) => write!(f, "invalid coordinate: {}", s),
+        }
+    }
+}
+impl error::Error for ParsePointError {}
+
+/// Point (or vector) with `N` integer coordinates.
+#[derive(PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy, Debug)]
+pub struct Point<const N: usize> {
+    pub coords: [Coord; N],
+}
+
+pub type Point2 = Point<2>;
+pub type Point3 = Point<3>;
+pub type Point4 = Point<4>;
+
+impl<const N: usize> Point<N> {
+    pub fn new(coords: [Coord; N]) -> Self {
+        Point { coords }
+    }
+    pub fn origin() -> Self {
+        Point { coords: [0; N] }
+    }
+    /// Sum of absolute coordinate differences (L1 distance).
+    pub fn manhattan(&self, other: &Self) -> Coord {
+        let mut d = 0;
+        for i in 0..N {
+            d += (self.coords[i] - other.coords[i]).abs();
+        }
+        return d;
+    }
+    /// Largest absolute coordinate difference (L-infinity distance).
+    pub fn chebyshev(&self, other: &Self) -> Coord {
+        let mut d = 0;
+        for i in 0..N {
+            d = cmp::max(d, (self.coords[i] - other.coords[i]).abs());
+        }
+        return d;
+    }
+    /// Manhattan distance from the origin.
+    pub fn norm(&self) -> Coord {
+        self.manhattan(&Self::origin())
+    }
+    /// Coordinate-wise minimum.
+    pub fn componentwise_min(&self, other: &Self) -> Self {
+        let mut coords = self.coords;
+        for i in 0..N {
+            coords[i] = cmp::min(coords[i], other.coords[i]);
+        }
+        return Point { coords };
+    }
+    /// Coordinate-wise maximum.
+    pub fn componentwise_max(&self, other: &Self) -> Self {
+        let mut coords = self.coords;
+        for i in 0..N {
+            coords[i] = cmp::max(coords[i], other.coords[i]);
+        }
+        return Point { coords };
+    }
+}
+
+impl Point<2> {
+    pub fn x(&self) -> Coord {
+        self.coords[0]
+    }
+    pub fn y(&self) -> Coord {
+        self.coords[1]
+    }
+}
+
+impl Point<3> {
+    pub fn x(&self) -> Coord {
+        self.coords[0]
+    }
+    pub fn y(&self) -> Coord {
+        self.coords[1]
+    }
+    pub fn z(&self) -> Coord {
+        self.coords[2]
+    }
+}
+
+impl<const N: usize> Default for Point<N> {
+    fn default() -> Self {
+        Self::origin()
+    }
+}
+
+impl<const N: usize> Index<usize> for Point<N> {
+    type Output = Coord;
+
+    fn index(&self, index: usize) -> &Coord {
+        &self.coords[index]
+    }
+}
+
+impl<const N: usize> IndexMut<usize> for Point<N> {
+    fn index_mut(&mut self, index: usize) -> &mut Coord {
+        &mut self.coords[index]
+    }
+}
+
+impl<const N: usize> Add for Point<N> {
+    type Output = Self;
+
+    fn add(mut self, other: Self) -> Self {
+        self += other;
+        self
+    }
+}
+
+impl<const N: usize> AddAssign for Point<N> {
+    fn add_assign(&mut self, other: Self) {
+        for i in 0..N {
+            self.coords[i] += other.coords[i];
+        }
+    }
+}
+
+impl<const N: usize> Sub for Point<N> {
+    type Output = Self;
+
+    fn sub(mut self, other: Self) -> Self {
+        self -= other;
+        self
+    }
+}
+
+impl<const N: usize> SubAssign for Point<N> {
+    fn sub_assign(&mut self, other: Self) {
+        for i in 0..N {
+            self.coords[i] -= other.coords[i];
+        }
+    }
+}
+
+impl<const N: usize> Neg for Point<N> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Point { coords: self.coords.map(|c| -c) }
+    }
+}
+
+impl<const N: usize> Mul<Coord> for Point<N> {
+    type Output = Self;
+
+    fn mul(self, f: Coord) -> Self {
+        Point { coords: self.coords.map(|c| c * f) }
+    }
+}
+
+impl<const N: usize> Div<Coord> for Point<N> {
+    type Output = Self;
+
+    fn div(self, f: Coord) -> Self {
+        Point { coords: self.coords.map(|c| c / f) }
+    }
+}
+
+impl<const N: usize> fmt::Display for Point<N> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let strs: Vec<String> = self.coords.iter().map(|c| c.to_string()).collect();
+        write!(f, "{}", strs.join(","))
+    }
+}
+
+/// Parses comma separated coordinates, optionally surrounded by angle
+/// brackets, e.g. `<1,-2,3>` or `1, -2`.
+impl<const N: usize> FromStr for Point<N> {
+    type Err = ParsePointError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let trimmed = s.trim();
+        let inner = match trimmed.strip_prefix('<') {
+            Some(rest) => match rest.strip_suffix('>') {
+                Some(inner) => inner,
+                None => return Err(ParsePointError::InvalidFormat(s.to_string())),
+            },
+            None => trimmed,
+        };
+        let segments: Vec<&str> = inner.split(',').map(|seg| seg.trim()).collect();
+        if segments.len() != N {
+            return Err(ParsePointError::InvalidFormat(s.to_string()));
+        }
+        let mut coords = [0; N];
+        for (i, seg) in segments.iter().enumerate() {
+            coords[i] = match seg.parse() {
+                Ok(c) => c,
+                Err(_) => return Err(ParsePointError::InvalidNumber(seg.to_string())),
+            };
+        }
+        return Ok(Point { coords });
+    }
+}
+
+/// Axis-aligned box; both `min` and `max` corners are inclusive.
+#[derive(PartialEq, Eq, Hash, Clone, Copy, Debug)]
+pub struct BoundingBox<const N: usize> {
+    pub min: Point<N>,
+    pub max: Point<N>,
+}
+
+impl<const N: usize> BoundingBox<N> {
+    pub fn new(min: Point<N>, max: Point<N>) -> Self {
+        BoundingBox { min, max }
+    }
+    pub fn from_point(point: Point<N>) -> Self {
+        BoundingBox { min: point, max: point }
+    }
+    /// Smallest box containing all the points, `None` if there are none.
+    pub fn from_points<'a>(points: impl IntoIterator<Item = &'a Point<N>>) -> Option<Self> {
+        let mut iter = points.into_iter();
+        let mut bbox = Self::from_point(*iter.next()?);
+        for p in iter {
+            bbox.expand(p);
+        }
+        return Some(bbox);
+    }
+    /// Grows the box (if needed) so that it contains given point.
+    pub fn expand(&mut self, point: &Point<N>) {
+        self.min = self.min.componentwise_min(point);
+        self.max = self.max.componentwise_max(point);
+    }
+    pub fn union(&self, other: &Self) -> Self {
+        BoundingBox {
+            min: self.min.componentwise_min(&other.min),
+            max: self.max.componentwise_max(&other.max),
+        }
+    }
+    pub fn contains(&self, point: &Point<N>) -> bool {
+        (0..N).all(|i| self.min.coords[i] <= point.coords[i] && point.coords[i] <= self.max.coords[i])
+    }
+    /// Number of integer positions along each axis.
+    pub fn extent(&self) -> Point<N> {
+        let mut coords = [0; N];
+        for i in 0..N {
+            coords[i] = self.max.coords[i] - self.min.coords[i] + 1;
+        }
+        return Point { coords };
+    }
+}
+
+/// Distance function between points.
+pub trait Metric {
+    fn dist<const N: usize>(&self, a: &Point<N>, b: &Point<N>) -> Coord;
+}
+
+#[derive(PartialEq, Eq, Clone, Copy, Debug, Default)]
+pub struct Manhattan;
+impl Metric for Manhattan {
+    fn dist<const N: usize>(&self, a: &Point<N>, b: &Point<N>) -> Coord {
+        a.manhattan(b)
+    }
+}
+
+#[derive(PartialEq, Eq, Clone, Copy, Debug, Default)]
+pub struct Chebyshev;
+impl Metric for Chebyshev {
+    fn dist<const N: usize>(&self, a: &Point<N>, b: &Point<N>) -> Coord {
+        a.chebyshev(b)
+    }
+}
+
+/// Squared Euclidean distance; it orders points by distance like the
+/// Euclidean one while staying integer.
+#[derive(PartialEq, Eq, Clone, Copy, Debug, Default)]
+pub struct SquaredEuclidean;
+impl Metric for SquaredEuclidean {
+    fn dist<const N: usize>(&self, a: &Point<N>, b: &Point<N>) -> Coord {
+        let mut d = 0;
+        for i in 0..N {
+            let diff = a.coords[i] - b.coords[i];
+            d += diff * diff;
+        }
+        return d;
+    }
+}
+
+/// Any of the metrics above, chosen at run time.
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub enum MetricKind {
+    Manhattan,
+    Chebyshev,
+    SquaredEuclidean,
+}
+impl Metric for MetricKind {
+    fn dist<const N: usize>(&self, a: &Point<N>, b: &Point<N>) -> Coord {
+        match self {
+            MetricKind::Manhattan => Manhattan.dist(a, b),
+            MetricKind::Chebyshev => Chebyshev.dist(a, b),
+            MetricKind::SquaredEuclidean => SquaredEuclidean.dist(a, b),
+        }
+    }
+}
+impl FromStr for MetricKind {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "manhattan" => Ok(MetricKind::Manhattan),
+            "chebyshev" => Ok(MetricKind::Chebyshev),
+            "euclidean" => Ok(MetricKind::SquaredEuclidean),
+            _ => Err(format!("unknown metric: {}", s)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_points() {
+        assert_eq!("<1,-2,3>".parse(), Ok(Point3::new([1, -2, 3])));
+        assert_eq!("1, -2".parse(), Ok(Point2::new([1, -2])));
+        assert_eq!("  < 9,  1> ".parse(), Ok(Point2::new([9, 1])));
+        assert_eq!("<-6,10>".parse::<Point2>().unwrap().to_string(), "-6,10");
+    }
+
+    #[test]
+    fn parse_point_errors() {
+        assert_eq!("<1,2".parse::<Point2>(), Err(ParsePointError::InvalidFormat("<1,2".to_string())));
+        assert_eq!("1,2,3".parse::<Point2>(), Err(ParsePointError::InvalidFormat("1,2,3".to_string())));
+        assert_eq!("".parse::<Point2>(), Err(ParsePointError::InvalidFormat("".to_string())));
+        assert_eq!("1, x".parse::<Point2>(), Err(ParsePointError::InvalidNumber("x".to_string())));
+        assert_eq!("<1,,3>".parse::<Point3>(), Err(ParsePointError::InvalidNumber("".to_string())));
+    }
+
+    #[test]
+    fn distances() {
+        let a = Point4::new([0, 3, -1, 2]);
+        let b = Point4::new([1, -1, -1, 4]);
+        assert_eq!(a.manhattan(&b), 7);
+        assert_eq!(a.chebyshev(&b), 4);
+        assert_eq!(a.norm(), 6);
+        assert_eq!(Manhattan.dist(&a, &b), 7);
+        assert_eq!(Chebyshev.dist(&a, &b), 4);
+        assert_eq!(SquaredEuclidean.dist(&a, &b), 21);
+        assert_eq!("euclidean".parse::<MetricKind>().unwrap().dist(&a, &b), 21);
+        assert!("taxicab".parse::<MetricKind>().is_err());
+    }
+
+    #[test]
+    fn bounding_boxes() {
+        let points = [Point2::new([2, -1]), Point2::new([-3, 4]), Point2::new([0, 0])];
+        let bbox = BoundingBox::from_points(&points).unwrap();
+        assert_eq!(bbox, BoundingBox::new(Point2::new([-3, -1]), Point2::new([2, 4])));
+        assert_eq!(bbox.extent(), Point2::new([6, 6]));
+        assert_eq!(BoundingBox::from_point(Point2::new([5, 5])).extent(), Point2::new([1, 1]));
+        assert_eq!(BoundingBox::<2>::from_points(&[]), None);
+
+        assert!(bbox.contains(&Point2::new([-3, 4])));
+        assert!(bbox.contains(&Point2::new([0, 2])));
+        assert!(!bbox.contains(&Point2::new([3, 0])));
+        assert!(!bbox.contains(&Point2::new([0, -2])));
+
+        let other = BoundingBox::new(Point2::new([1, -5]), Point2::new([7, 0]));
+        assert_eq!(bbox.union(&other), BoundingBox::new(Point2::new([-3, -5]), Point2::new([7, 4])));
+        assert_eq!(bbox.union(&bbox), bbox);
+        let mut expanded = bbox;
+        expanded.expand(&Point2::new([7, -5]));
+        assert_eq!(expanded, bbox.union(&other));
+    }
+}
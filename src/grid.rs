@@ -0,0 +1,218 @@
+use std::fmt;
+use std::ops::Index;
+use std::ops::IndexMut;
+
+/// Position of a cell in the grid as `(x, y)`, where `x` is the column
+/// and `y` is the row.
+pub type Pos = (usize, usize);
+
+const NEIGHBOUR4_DELTAS: [(isize, isize); 4] = [
+    (0, -1),
+    (-1, 0),
+    (1, 0),
+    (0, 1),
+];
+
+const NEIGHBOUR8_DELTAS: [(isize, isize); 8] = [
+    (-1, -1),
+    (0, -1),
+    (1, -1),
+    (-1, 0),
+    (1, 0),
+    (-1, 1),
+    (0, 1),
+    (1, 1),
+];
+
+/// Cells which can be rendered as a single character.
+pub trait ToChar {
+    fn to_char(&self) -> char;
+}
+
+/// Two-dimensional grid stored as a flat, row-major vector.
+#[derive(PartialEq, Eq, Hash, Clone, Debug)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T: Clone> Grid<T> {
+    pub fn new(width: usize, height: usize, value: T) -> Grid<T> {
+        Grid { width, height, cells: vec![value; width * height] }
+    }
+}
+
+impl<T> Grid<T> {
+    pub fn from_vec(width: usize, height: usize, cells: Vec<T>) -> Grid<T> {
+        assert!(cells.len() == width * height, "invalid number of cells");
+        Grid { width, height, cells }
+    }
+
+    /// Parses a character map, one row per line. Rows shorter than the longest
+    /// one are padded with spaces. The conversion function receives the
+    /// position and the character of every cell.
+    pub fn parse<E>(s: &str, mut f: impl FnMut(Pos, char) -> Result<T, E>) -> Result<Grid<T>, E> {
+        let lines: Vec<&str> = s.lines().collect();
+        let width = lines.iter().map(|l| l.chars().count()).max().unwrap_or(0);
+        let height = lines.len();
+        let mut cells = Vec::with_capacity(width * height);
+        for (y, line) in lines.iter().enumerate() {
+            let mut chars = line.chars();
+            for x in 0..width {
+                let c = chars.next().unwrap_or(' ');
+                cells.push(f((x, y), c)?);
+            }
+        }
+        return Ok(Grid { width, height, cells });
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, (x, y): Pos) -> bool {
+        x < self.width && y < self.height
+    }
+
+    pub fn get(&self, pos: Pos) -> Option<&T> {
+        if self.contains(pos) {
+            Some(&self.cells[pos.1 * self.width + pos.0])
+        } else {
+            None
+        }
+    }
+
+    pub fn get_mut(&mut self, pos: Pos) -> Option<&mut T> {
+        if self.contains(pos) {
+            Some(&mut self.cells[pos.1 * self.width + pos.0])
+        } else {
+            None
+        }
+    }
+
+    /// Iterates over all cells in row-major order.
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        self.cells.iter()
+    }
+
+    /// Iterates over all positions in row-major order.
+    pub fn positions(&self) -> impl Iterator<Item = Pos> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| (x, y)))
+    }
+
+    /// Positions of the (up to 4) orthogonally adjacent cells.
+    pub fn neighbours4(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        self.neighbours(pos, &NEIGHBOUR4_DELTAS)
+    }
+
+    /// Positions of the (up to 8) orthogonally and diagonally adjacent cells.
+    pub fn neighbours8(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        self.neighbours(pos, &NEIGHBOUR8_DELTAS)
+    }
+
+    fn neighbours(&self, (x, y): Pos, deltas: &'static [(isize, isize)]) -> impl Iterator<Item = Pos> + '_ {
+        deltas.iter().filter_map(move |(dx, dy)| {
+            let nx = x.checked_add_signed(*dx)?;
+            let ny = y.checked_add_signed(*dy)?;
+            if self.contains((nx, ny)) { Some((nx, ny)) } else { None }
+        })
+    }
+
+    pub fn map<U>(&self, f: impl Fn(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+}
+
+impl<T> Index<Pos> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: Pos) -> &T {
+        self.get(pos).unwrap_or_else(|| panic!("position {:?} out of grid bounds", pos))
+    }
+}
+
+impl<T> IndexMut<Pos> for Grid<T> {
+    fn index_mut(&mut self, pos: Pos) -> &mut T {
+        self.get_mut(pos).unwrap_or_else(|| panic!("position {:?} out of grid bounds", pos))
+    }
+}
+
+impl<T: ToChar> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for row in self.cells.chunks(self.width.max(1)) {
+            let line: String = row.iter().map(|cell| cell.to_char()).collect();
+            writeln!(f, "{}", line)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    impl ToChar for char {
+        fn to_char(&self) -> char {
+            *self
+        }
+    }
+
+    fn parse_chars(s: &str) -> Grid<char> {
+        return Grid::parse(s, |_, c| Ok::<char, ()>(c)).unwrap();
+    }
+
+    #[test]
+    fn row_major_order() {
+        let grid = parse_chars("abc\ndef\n");
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[(2, 0)], 'c');
+        assert_eq!(grid[(0, 1)], 'd');
+        assert_eq!(grid.get((3, 0)), None);
+        assert_eq!(grid.get((0, 2)), None);
+        assert_eq!(grid.iter().collect::<String>(), "abcdef");
+        assert_eq!(grid.positions().collect::<Vec<_>>(), [(0, 0), (1, 0), (2, 0), (0, 1), (1, 1), (2, 1)]);
+        assert_eq!(Grid::from_vec(3, 2, "abcdef".chars().collect()), grid);
+    }
+
+    #[test]
+    fn ragged_rows() {
+        // Shorter rows are padded with spaces; rejecting cells is up to the
+        // conversion function, which gets their positions.
+        let grid = parse_chars("ab\nc\n");
+        assert_eq!(grid.to_string(), "ab\nc \n");
+        let strict = Grid::parse("ab\nc\n", |pos, c| if c == ' ' { Err(pos) } else { Ok(c) });
+        assert_eq!(strict, Err((1, 1)));
+    }
+
+    #[test]
+    fn display_round_trip() {
+        let map = "#.#\n..#\n#..\n";
+        let grid = parse_chars(map);
+        assert_eq!(grid.to_string(), map);
+        assert_eq!(parse_chars(&grid.to_string()), grid);
+        let mut changed = grid.map(|c| if *c == '#' { '.' } else { '#' });
+        changed[(1, 2)] = '+';
+        assert_eq!(changed.to_string(), ".#.\n##.\n.+#\n");
+    }
+
+    #[test]
+    fn neighbours() {
+        let grid = Grid::new(3, 2, 0);
+        assert_eq!(grid.neighbours4((0, 0)).collect::<Vec<_>>(), [(1, 0), (0, 1)]);
+        assert_eq!(grid.neighbours4((2, 1)).collect::<Vec<_>>(), [(2, 0), (1, 1)]);
+        assert_eq!(grid.neighbours4((1, 0)).collect::<Vec<_>>(), [(0, 0), (2, 0), (1, 1)]);
+        assert_eq!(Grid::new(3, 3, 0).neighbours4((1, 1)).count(), 4);
+        assert_eq!(grid.neighbours8((0, 0)).collect::<Vec<_>>(), [(1, 0), (0, 1), (1, 1)]);
+        assert_eq!(grid.neighbours8((1, 1)).count(), 5);
+    }
+}
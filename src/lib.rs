@@ -0,0 +1,334 @@
+#![allow(clippy::needless_return)]
+#![allow(clippy::needless_range_loop)]
+#![allow(clippy::upper_case_acronyms)]
+#![allow(clippy::enum_variant_names)]
+#![allow(clippy::single_match)]
+#![allow(clippy::collapsible_match)]
+#![allow(clippy::match_like_matches_macro)]
+
+use std::error;
+use std::fmt;
+use std::fs;
+use std::path::Path;
+
+use crate::diagnostics::Diagnostics;
+use crate::geometry::MetricKind;
+use crate::params::ParamError;
+use crate::params::Params;
+use crate::parse::ParseError;
+
+pub mod bench;
+pub mod diagnostics;
+pub mod geometry;
+pub mod grid;
+pub mod params;
+pub mod parse;
+
+pub mod day01;
+pub mod day02;
+pub mod day03;
+pub mod day04;
+pub mod day05;
+pub mod day06;
+pub mod day07;
+pub mod day08;
+pub mod day09;
+pub mod day10;
+pub mod day11;
+pub mod day12;
+pub mod day13;
+pub mod day16;
+pub mod day18;
+pub mod day19;
+pub mod day20;
+pub mod day22;
+pub mod day23;
+pub mod day24;
+pub mod day25;
+
+/// Answer to a single puzzle part.
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub enum Answer {
+    Integer(i64),
+    Text(String),
+    Coordinate(i64, i64),
+}
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Answer::Integer(n) => write!(f, "{}", n),
+            Answer::Text(s) => write!(f, "{}", s.trim_end()),
+            Answer::Coordinate(x, y) => write!(f, "{},{}", x, y),
+        }
+    }
+}
+impl From<i32> for Answer {
+    fn from(n: i32) -> Self {
+        Answer::Integer(n as i64)
+    }
+}
+impl From<usize> for Answer {
+    fn from(n: usize) -> Self {
+        Answer::Integer(n as i64)
+    }
+}
+impl From<u32> for Answer {
+    fn from(n: u32) -> Self {
+        Answer::Integer(n as i64)
+    }
+}
+impl From<String> for Answer {
+    fn from(s: String) -> Self {
+        Answer::Text(s)
+    }
+}
+impl From<(i32, i32)> for Answer {
+    fn from((x, y): (i32, i32)) -> Self {
+        Answer::Coordinate(x as i64, y as i64)
+    }
+}
+impl From<(usize, usize)> for Answer {
+    fn from((x, y): (usize, usize)) -> Self {
+        Answer::Coordinate(x as i64, y as i64)
+    }
+}
+
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub enum SolveError {
+    NotImplemented { day: u32, part: u32 },
+    NoAnswer { day: u32, part: u32 },
+    Parse { day: u32, error: ParseError },
+    Param { day: u32, error: ParamError },
+    Output { day: u32, path: String, message: String },
+}
+impl fmt::Display for SolveError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            SolveError::NotImplemented { day, part } => write!(f, "day {} part {} is not implemented", day, part),
+            SolveError::NoAnswer { day, part } => write!(f, "day {} part {} has no answer for given input", day, part),
+            SolveError::Parse { day, error } => write!(f, "invalid day {} input: {}", day, error),
+            SolveError::Param { day, error } => write!(f, "invalid day {} parameter {}", day, error),
+            SolveError::Output { day, path, message } => write!(f, "cannot write day {} output {}: {}", day, path, message),
+        }
+    }
+}
+impl error::Error for SolveError {}
+
+fn some<T: Into<Answer>>(answer: T) -> Option<Answer> {
+    Some(answer.into())
+}
+
+fn opt<T: Into<Answer>>(answer_opt: Option<T>) -> Option<Answer> {
+    answer_opt.map(|answer| answer.into())
+}
+
+fn output_error(day: u32, path: &str, error: impl fmt::Display) -> SolveError {
+    SolveError::Output { day, path: path.to_string(), message: error.to_string() }
+}
+
+/// Writes the `--heatmap` PNG and the `--conflicts` CSV of the day 3 claims
+/// after part 1.
+fn write_day03_reports(input: &str, params: &Params) -> Result<(), SolveError> {
+    let heatmap_path = params.get("heatmap");
+    let conflicts_path = params.get("conflicts");
+    if heatmap_path.is_none() && conflicts_path.is_none() {
+        return Ok(());
+    }
+    let claims = day03::claim::parse_claims(input).map_err(|error| SolveError::Parse { day: 3, error })?;
+    match heatmap_path {
+        Some(path) => match day03::report::heatmap(&claims) {
+            Some(img) => img.save(path).map_err(|err| output_error(3, path, err))?,
+            None => return Err(output_error(3, path, "no claims to draw")),
+        },
+        None => {},
+    }
+    match conflicts_path {
+        Some(path) => {
+            let csv = day03::report::conflicts_csv(&day03::report::conflicts(&claims));
+            fs::write(path, csv).map_err(|err| output_error(3, path, err))?;
+        },
+        None => {},
+    }
+    return Ok(());
+}
+
+/// Accepted values of the day 6 `--metric` parameter.
+const METRIC_NAMES: &str = "manhattan, chebyshev or euclidean";
+
+/// Writes the `--render` PNG of the day 6 ownership map under the
+/// `--metric` distance after part 1, with the part 2 safe region below
+/// `--limit` overlaid within the bounding box.
+fn write_day06_render(input: &str, params: &Params) -> Result<(), SolveError> {
+    let path = match params.get("render") {
+        Some(path) => path,
+        None => return Ok(()),
+    };
+    let param_error = |error| SolveError::Param { day: 6, error };
+    let metric = params.parse_or("metric", METRIC_NAMES, MetricKind::Manhattan).map_err(param_error)?;
+    let limit = params.parse_or("limit", "integer", day06::safe_region::DEFAULT_LIMIT).map_err(param_error)?;
+    let locations = day06::coordinates::parse_coordinates(input).map_err(|error| SolveError::Parse { day: 6, error })?;
+    let map = day06::largest_area::OwnershipMap::new(&locations, &metric).unwrap();
+    day06::render::ownership_image(&map, Some(limit)).save(path).map_err(|err| output_error(6, path, err))?;
+    return Ok(());
+}
+
+/// Writes the `--dot` graph of the day 7 steps after part 2, highlighting
+/// the critical path for the `--base` step duration.
+fn write_day07_dot(input: &str, params: &Params) -> Result<(), SolveError> {
+    let path = match params.get("dot") {
+        Some(path) => path,
+        None => return Ok(()),
+    };
+    let base_duration = params.parse_or("base", "integer", day07::assembly_time::DEFAULT_BASE_DURATION)
+        .map_err(|error| SolveError::Param { day: 7, error })?;
+    let graph = day07::graph::parse_graph(input).map_err(|error| SolveError::Parse { day: 7, error })?;
+    fs::write(path, day07::dot::to_dot(&graph, base_duration)).map_err(|err| output_error(7, path, err))?;
+    return Ok(());
+}
+
+/// Writes `--iterations` frames of the day 10 sky after part 1, every
+/// `--stride` seconds from second `--start`, as PNG files into the
+/// `--frames` directory.
+fn write_day10_frames(input: &str, params: &Params, diagnostics: &mut Diagnostics) -> Result<(), SolveError> {
+    let dir = match params.get("frames") {
+        Some(dir) => dir,
+        None => return Ok(()),
+    };
+    let param_error = |error| SolveError::Param { day: 10, error };
+    let iterations = params.parse_or("iterations", "integer", day10::stars_alignment::DEFAULT_ITERATIONS).map_err(param_error)?;
+    let start = params.parse_or("start", "integer", 0).map_err(param_error)?;
+    let stride = params.parse_positive_or("stride", 1).map_err(param_error)?;
+    let frames = day10::stars_alignment::frames(input, start, stride, diagnostics)
+        .map_err(|error| SolveError::Parse { day: 10, error })?;
+    for (i, frame) in frames.take(iterations).enumerate() {
+        let path = Path::new(dir).join(format!("out_{:04}.png", i));
+        diagnostics.trace(|| format!("writing {}", path.display()));
+        frame.save(&path).map_err(|err| output_error(10, &path.to_string_lossy(), err))?;
+    }
+    return Ok(());
+}
+
+/// Parts accepted by `solve`, as (day, part) pairs.
+pub const IMPLEMENTED_PARTS: &[(u32, u32)] = &[
+    (1, 1), (1, 2), (2, 1), (2, 2), (3, 1), (3, 2), (4, 1), (4, 2), (5, 1), (5, 2),
+    (6, 1), (6, 2), (7, 1), (7, 2), (8, 1), (9, 1), (10, 1), (11, 1), (12, 1), (13, 1),
+    (16, 1), (18, 1), (19, 1), (20, 1), (22, 1), (23, 1), (24, 1), (25, 1),
+];
+
+/// Solves given part of the puzzle from given day for the puzzle input text.
+pub fn solve(day: u32, part: u32, input: &str) -> Result<Answer, SolveError> {
+    return solve_with(day, part, input, &Params::new(), &mut Diagnostics::new());
+}
+
+/// Like `solve`, with optional solver parameters, collecting the solver's
+/// side information into `diagnostics`.
+pub fn solve_with(day: u32, part: u32, input: &str, params: &Params, diagnostics: &mut Diagnostics) -> Result<Answer, SolveError> {
+    let param_error = |error| SolveError::Param { day, error };
+    let result: Result<Option<Answer>, ParseError> = match (day, part) {
+        (1, 1) => day01::sum::solve_with(input, diagnostics).map(some),
+        (1, 2) => day01::first_freq_twice::solve_with(input, diagnostics).map(opt),
+        (2, 1) => {
+            let counts = params.parse_list_or("counts", "comma-separated integers", day02::checksum::DEFAULT_COUNTS.to_vec())
+                .map_err(param_error)?;
+            Ok(some(day02::checksum::solve_with(input, &counts, diagnostics)))
+        },
+        (2, 2) => {
+            let max_mismatches = params.parse_positive_or("mismatches", day02::common_letters::DEFAULT_MISMATCHES).map_err(param_error)?;
+            Ok(opt(day02::common_letters::solve_with(input, max_mismatches, diagnostics)))
+        },
+        (3, 1) => day03::overlap::solve(input).map(some),
+        (3, 2) => day03::unique_claim::solve(input).map(opt),
+        (4, 1) => day04::sleepiest_guard::solve_with(input, diagnostics).map(opt),
+        (4, 2) => day04::most_frequent_minute::solve_with(input, diagnostics).map(opt),
+        (5, 1) => Ok(some(day05::polymer_reaction::solve_with(input, diagnostics))),
+        (5, 2) => {
+            let num_of_threads = params.parse_or("threads", "integer", 1).map_err(param_error)?;
+            Ok(some(day05::most_blocking_polymer::solve_with(input, num_of_threads, diagnostics)))
+        },
+        (6, 1) => {
+            let metric = params.parse_or("metric", METRIC_NAMES, MetricKind::Manhattan).map_err(param_error)?;
+            day06::largest_area::solve_with(input, &metric, diagnostics).map(some)
+        },
+        (6, 2) => {
+            let limit = params.parse_or("limit", "integer", day06::safe_region::DEFAULT_LIMIT).map_err(param_error)?;
+            day06::safe_region::solve_with(input, limit).map(some)
+        },
+        (7, 1) => day07::step_order::solve(input).map(some),
+        (7, 2) => {
+            let num_of_workers = params.parse_positive_or("workers", day07::assembly_time::DEFAULT_WORKERS).map_err(param_error)?;
+            let base_duration = params.parse_or("base", "integer", day07::assembly_time::DEFAULT_BASE_DURATION).map_err(param_error)?;
+            day07::assembly_time::solve_with(input, num_of_workers, base_duration, diagnostics).map(some)
+        },
+        (8, 1) => day08::metadata_sum::solve(input).map(some),
+        (9, 1) => day09::winning_score::solve(input).map(some),
+        (10, 1) => day10::stars_alignment::solve(input).map(some),
+        (11, 1) => day11::largest_total_power::solve_with(input, diagnostics).map(some),
+        (12, 1) => day12::plants_sustainability::solve(input).map(some),
+        (13, 1) => day13::first_crash::solve(input).map(some),
+        (16, 1) => day16::guess_opcodes::solve(input).map(some),
+        (18, 1) => day18::resources::solve(input).map(some),
+        (19, 1) => day19::ip_register::solve(input).map(opt),
+        (20, 1) => day20::largest_num_of_doors::solve(input).map(some),
+        (22, 1) => day22::total_risk_level::solve_with(input, diagnostics).map(some),
+        (23, 1) => day23::nanobots_in_range::solve(input).map(some),
+        (24, 1) => day24::immune_system_simulation::solve_with(input, diagnostics).map(some),
+        (25, 1) => day25::constellation::solve(input).map(some),
+        _ => return Err(SolveError::NotImplemented { day, part }),
+    };
+    let answer = match result {
+        Ok(Some(answer)) => answer,
+        Ok(None) => return Err(SolveError::NoAnswer { day, part }),
+        Err(error) => return Err(SolveError::Parse { day, error }),
+    };
+    // Extra outputs are written only once their part is solved.
+    match (day, part) {
+        (3, 1) => write_day03_reports(input, params)?,
+        (6, 1) => write_day06_render(input, params)?,
+        (7, 2) => write_day07_dot(input, params)?,
+        (10, 1) => write_day10_frames(input, params, diagnostics)?,
+        _ => {},
+    }
+    return Ok(answer);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn implemented_parts_match_solve() {
+        for day in 1..=25 {
+            for part in 1..=2 {
+                if IMPLEMENTED_PARTS.contains(&(day, part)) {
+                    continue;
+                }
+                assert_eq!(solve(day, part, ""), Err(SolveError::NotImplemented { day, part }));
+            }
+        }
+    }
+
+    #[test]
+    fn zero_workers() {
+        let mut params = Params::new();
+        params.set("workers", "0");
+        let error = ParamError { name: "workers".to_string(), value: "0".to_string(), expected: "positive integer".to_string() };
+        let input = "Step A must be finished before step B can begin.\n";
+        assert_eq!(solve_with(7, 2, input, &params, &mut Diagnostics::new()), Err(SolveError::Param { day: 7, error }));
+    }
+
+    #[test]
+    fn outputs_follow_their_part() {
+        let path = std::env::temp_dir().join(format!("aoc2018-day07-{}.dot", std::process::id()));
+        let mut params = Params::new();
+        params.set("dot", path.to_str().unwrap());
+        let input = "Step A must be finished before step B can begin.\n";
+        assert_eq!(solve_with(7, 1, input, &params, &mut Diagnostics::new()), Ok(Answer::Text("AB".to_string())));
+        assert!(!path.exists());
+        let cyclic = "Step A must be finished before step A can begin.\n";
+        assert!(solve_with(7, 2, cyclic, &params, &mut Diagnostics::new()).is_err());
+        assert!(!path.exists());
+        assert_eq!(solve_with(7, 2, input, &params, &mut Diagnostics::new()), Ok(Answer::Integer(61 + 62)));
+        assert!(path.exists());
+        fs::remove_file(&path).unwrap();
+    }
+}
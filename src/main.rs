@@ -0,0 +1,179 @@
+use std::env;
+use std::fs;
+use std::io;
+use std::io::Read;
+use std::path::Path;
+use std::process;
+use std::time::Instant;
+
+use aoc2018::bench;
+use aoc2018::diagnostics::Diagnostics;
+use aoc2018::params::Params;
+use aoc2018::Answer;
+use aoc2018::SolveError;
+
+#[cfg(feature = "peak-memory")]
+#[global_allocator]
+static ALLOCATOR: bench::PeakAllocator = bench::PeakAllocator;
+
+const DEFAULT_INPUTS_DIR: &str = "inputs";
+
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+enum Format {
+    Text,
+    Json,
+}
+
+struct Options {
+    format: Format,
+    verbose: bool,
+    params: Params,
+    args: Vec<String>,
+}
+
+fn usage() -> ! {
+    eprintln!("usage: aoc2018 [--format text|json] [--verbose] [--<param> <value>]... <day> <part> [input]");
+    eprintln!("       aoc2018 --bench-all [inputs-dir]");
+    process::exit(2);
+}
+
+fn parse_arg(arg: &str) -> u32 {
+    match arg.parse() {
+        Ok(n) => n,
+        Err(_) => usage(),
+    }
+}
+
+fn parse_options(args: &[String]) -> Options {
+    let mut options = Options { format: Format::Text, verbose: false, params: Params::new(), args: Vec::new() };
+    let mut iter = args.iter();
+    while let Some(arg) = iter.next() {
+        match arg.as_str() {
+            "--verbose" => options.verbose = true,
+            "--format" => {
+                options.format = match iter.next().map(|s| s.as_str()) {
+                    Some("text") => Format::Text,
+                    Some("json") => Format::Json,
+                    _ => usage(),
+                };
+            },
+            _ if arg.starts_with("--") => {
+                match iter.next() {
+                    Some(value) => options.params.set(&arg[2..], value),
+                    None => usage(),
+                }
+            },
+            _ => options.args.push(arg.clone()),
+        }
+    }
+    options
+}
+
+fn read_input(path_opt: Option<&String>) -> String {
+    let result = match path_opt {
+        Some(path) => fs::read_to_string(path),
+        None => {
+            let mut input = String::new();
+            io::stdin().read_to_string(&mut input).map(|_| input)
+        },
+    };
+    result.unwrap_or_else(|err| {
+        eprintln!("cannot read input: {}", err);
+        process::exit(1);
+    })
+}
+
+fn json_string(s: &str) -> String {
+    let mut escaped = String::from("\"");
+    for c in s.chars() {
+        match c {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            '\t' => escaped.push_str("\\t"),
+            c if (c as u32) < 0x20 => escaped.push_str(&format!("\\u{:04x}", c as u32)),
+            c => escaped.push(c),
+        }
+    }
+    escaped.push('"');
+    escaped
+}
+
+fn json_answer(answer: &Answer) -> String {
+    match answer {
+        Answer::Integer(n) => n.to_string(),
+        _ => json_string(&answer.to_string()),
+    }
+}
+
+fn print_json(day: u32, part: u32, result: &Result<Answer, SolveError>, elapsed_ms: f64, diagnostics: &Diagnostics) {
+    let mut fields: Vec<String> = diagnostics.values().iter()
+        .map(|(key, value)| format!("{}:{}", json_string(key), json_answer(value)))
+        .collect();
+    if diagnostics.is_tracing() {
+        let lines: Vec<String> = diagnostics.trace_lines().iter().map(|line| json_string(line)).collect();
+        fields.push(format!("\"trace\":[{}]", lines.join(",")));
+    }
+    let answer = match result {
+        Ok(answer) => json_answer(answer),
+        Err(_) => "null".to_string(),
+    };
+    let error = match result {
+        Ok(_) => String::new(),
+        Err(err) => format!(",\"error\":{}", json_string(&err.to_string())),
+    };
+    println!("{{\"day\":{},\"part\":{},\"answer\":{},\"elapsed_ms\":{:.3},\"diagnostics\":{{{}}}{}}}",
+        day, part, answer, elapsed_ms, fields.join(","), error);
+}
+
+fn print_text(result: &Result<Answer, SolveError>, diagnostics: &Diagnostics) {
+    for (key, value) in diagnostics.values() {
+        eprintln!("{}: {}", key, value);
+    }
+    for line in diagnostics.trace_lines() {
+        eprintln!("{}", line);
+    }
+    match result {
+        Ok(answer) => println!("{}", answer),
+        Err(err) => eprintln!("{}", err),
+    }
+}
+
+fn bench_all(inputs_dir: &str) {
+    let measurements = bench::run_all(Path::new(inputs_dir), 1);
+    print!("{}", bench::format_table(&measurements));
+}
+
+fn main() {
+    let args: Vec<String> = env::args().collect();
+    if args.len() >= 2 && args[1] == "--bench-all" {
+        if args.len() > 3 {
+            usage();
+        }
+        bench_all(args.get(2).map_or(DEFAULT_INPUTS_DIR, |dir| dir.as_str()));
+        return;
+    }
+    let options = parse_options(&args[1..]);
+    if options.args.len() < 2 || options.args.len() > 3 {
+        usage();
+    }
+    let day = parse_arg(&options.args[0]);
+    let part = parse_arg(&options.args[1]);
+    let input = read_input(options.args.get(2));
+
+    let mut diagnostics = match options.verbose {
+        true => Diagnostics::with_trace(),
+        false => Diagnostics::new(),
+    };
+    let start = Instant::now();
+    let result = aoc2018::solve_with(day, part, &input, &options.params, &mut diagnostics);
+    let elapsed_ms = start.elapsed().as_secs_f64() * 1000.0;
+
+    match options.format {
+        Format::Text => print_text(&result, &diagnostics),
+        Format::Json => print_json(day, part, &result, elapsed_ms, &diagnostics),
+    }
+    if result.is_err() {
+        process::exit(1);
+    }
+}
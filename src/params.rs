@@ -0,0 +1,100 @@
+//! Optional solver parameters, given as `--name value` on the command line.
+
+use std::error;
+use std::fmt;
+use std::str::FromStr;
+
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub struct ParamError {
+    pub name: String,
+    pub value: String,
+    pub expected: String,
+}
+impl fmt::Display for ParamError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "--{} {:?}: expected {}", self.name, self.value, self.expected)
+    }
+}
+impl error::Error for ParamError {}
+
+const POSITIVE_INTEGER: &str = "positive integer";
+
+/// Named parameter values; solvers fall back to their defaults for the
+/// missing ones.
+#[derive(PartialEq, Eq, Clone, Debug, Default)]
+pub struct Params {
+    values: Vec<(String, String)>,
+}
+impl Params {
+    pub fn new() -> Params {
+        Params { values: Vec::new() }
+    }
+    /// Sets the value of given parameter, replacing the previous one.
+    pub fn set(&mut self, name: &str, value: &str) {
+        match self.values.iter_mut().find(|(n, _)| n == name) {
+            Some(entry) => entry.1 = value.to_string(),
+            None => self.values.push((name.to_string(), value.to_string())),
+        }
+    }
+    pub fn get(&self, name: &str) -> Option<&str> {
+        return self.values.iter().find(|(n, _)| n == name).map(|(_, v)| v.as_str());
+    }
+    /// Parses the value of given parameter, or returns the default.
+    pub fn parse_or<T: FromStr>(&self, name: &str, expected: &str, default: T) -> Result<T, ParamError> {
+        match self.get(name) {
+            Some(value) => return value.trim().parse().map_err(|_| self.error(name, expected)),
+            None => return Ok(default),
+        }
+    }
+    /// Parses the comma-separated values of given parameter, or returns the
+    /// default.
+    pub fn parse_list_or<T: FromStr>(&self, name: &str, expected: &str, default: Vec<T>) -> Result<Vec<T>, ParamError> {
+        match self.get(name) {
+            Some(value) => {
+                let mut list = Vec::new();
+                for item in value.split(',') {
+                    list.push(item.trim().parse().map_err(|_| self.error(name, expected))?);
+                }
+                return Ok(list);
+            },
+            None => return Ok(default),
+        }
+    }
+    /// Parses the value of given parameter as a positive integer, or returns
+    /// the default.
+    pub fn parse_positive_or(&self, name: &str, default: usize) -> Result<usize, ParamError> {
+        let value = self.parse_or(name, POSITIVE_INTEGER, default)?;
+        if value == 0 {
+            return Err(self.error(name, POSITIVE_INTEGER));
+        }
+        return Ok(value);
+    }
+    fn error(&self, name: &str, expected: &str) -> ParamError {
+        return ParamError {
+            name: name.to_string(),
+            value: self.get(name).unwrap_or("").to_string(),
+            expected: expected.to_string(),
+        };
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_values() {
+        let mut params = Params::new();
+        params.set("counts", "2, 3,4");
+        params.set("limit", "x");
+        assert_eq!(params.parse_list_or("counts", "integers", vec![2, 3]), Ok(vec![2, 3, 4]));
+        assert_eq!(params.parse_list_or("other", "integers", vec![2, 3]), Ok(vec![2, 3]));
+        assert_eq!(params.parse_or("workers", "integer", 5), Ok(5));
+        assert_eq!(params.parse_or::<usize>("limit", "integer", 5).unwrap_err().to_string(),
+            "--limit \"x\": expected integer");
+        params.set("workers", "0");
+        assert_eq!(params.parse_positive_or("workers", 5).unwrap_err().to_string(),
+            "--workers \"0\": expected positive integer");
+        assert_eq!(params.parse_positive_or("threads", 1), Ok(1));
+    }
+}
@@ -0,0 +1,161 @@
+//! Helpers for parsing puzzle inputs with errors pointing at the offending
+//! line and column.
+
+use std::error;
+use std::fmt;
+use std::str::FromStr;
+
+/// Malformed input. Lines and columns are counted from 1.
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub struct ParseError {
+    pub line: usize,
+    pub column: usize,
+    pub expected: String,
+    pub found: String,
+}
+impl ParseError {
+    pub fn new(line: usize, column: usize, expected: impl Into<String>, found: impl Into<String>) -> ParseError {
+        ParseError { line, column, expected: expected.into(), found: found.into() }
+    }
+}
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "line {}, column {}: expected {}, found {}", self.line, self.column, self.expected, self.found)
+    }
+}
+impl error::Error for ParseError {}
+
+/// Describes the text at the error position, as used in `ParseError::found`.
+pub fn describe(s: &str) -> String {
+    match s.chars().next() {
+        None => "end of line".to_string(),
+        Some(c) if c.is_whitespace() => "whitespace".to_string(),
+        Some(_) => format!("{:?}", s.split_whitespace().next().unwrap_or(s)),
+    }
+}
+
+/// Iterates over the lines of the input, wrapped in `LineParser`s.
+pub fn lines(input: &str) -> impl Iterator<Item = LineParser<'_>> {
+    input.lines().enumerate().map(|(i, l)| LineParser::new(i + 1, l))
+}
+
+/// Cursor over a single line of the input.
+#[derive(Clone, Debug)]
+pub struct LineParser<'a> {
+    number: usize,
+    text: &'a str,
+    pos: usize,
+}
+impl<'a> LineParser<'a> {
+    pub fn new(number: usize, text: &'a str) -> LineParser<'a> {
+        LineParser { number, text, pos: 0 }
+    }
+
+    pub fn number(&self) -> usize {
+        self.number
+    }
+
+    pub fn text(&self) -> &'a str {
+        self.text
+    }
+
+    /// Column of the next unread character.
+    pub fn column(&self) -> usize {
+        self.text[..self.pos].chars().count() + 1
+    }
+
+    /// Unread part of the line.
+    pub fn rest(&self) -> &'a str {
+        &self.text[self.pos..]
+    }
+
+    pub fn is_blank(&self) -> bool {
+        self.text.trim().is_empty()
+    }
+
+    /// Error at the current position.
+    pub fn error(&self, expected: impl Into<String>) -> ParseError {
+        ParseError::new(self.number, self.column(), expected, describe(self.rest()))
+    }
+
+    /// Error at given column of this line.
+    pub fn error_at(&self, column: usize, expected: impl Into<String>, found: impl Into<String>) -> ParseError {
+        ParseError::new(self.number, column, expected, found)
+    }
+
+    pub fn skip_whitespace(&mut self) {
+        let rest = self.rest();
+        self.pos += rest.len() - rest.trim_start().len();
+    }
+
+    /// Consumes given text, which has to follow immediately.
+    pub fn literal(&mut self, s: &str) -> Result<(), ParseError> {
+        if self.rest().starts_with(s) {
+            self.pos += s.len();
+            return Ok(());
+        }
+        return Err(self.error(format!("{:?}", s)));
+    }
+
+    /// Consumes a single character satisfying the predicate.
+    pub fn char_matching(&mut self, expected: &str, pred: impl Fn(char) -> bool) -> Result<char, ParseError> {
+        match self.rest().chars().next() {
+            Some(c) if pred(c) => {
+                self.pos += c.len_utf8();
+                return Ok(c);
+            },
+            _ => return Err(self.error(expected)),
+        }
+    }
+
+    /// Consumes the longest (possibly empty) prefix of characters satisfying
+    /// the predicate.
+    pub fn take_while(&mut self, pred: impl Fn(char) -> bool) -> &'a str {
+        let rest = self.rest();
+        let len = rest.find(|c| !pred(c)).unwrap_or(rest.len());
+        self.pos += len;
+        return &rest[..len];
+    }
+
+    /// Consumes a non-empty run of non-whitespace characters, skipping the
+    /// leading whitespace.
+    pub fn word(&mut self, expected: &str) -> Result<&'a str, ParseError> {
+        self.skip_whitespace();
+        let column = self.column();
+        let word = self.take_while(|c| !c.is_whitespace());
+        if word.is_empty() {
+            return Err(self.error_at(column, expected, describe(self.rest())));
+        }
+        return Ok(word);
+    }
+
+    /// Consumes an optionally signed decimal integer, skipping the leading
+    /// whitespace.
+    pub fn integer<T: FromStr>(&mut self) -> Result<T, ParseError> {
+        self.skip_whitespace();
+        let start = self.pos;
+        let column = self.column();
+        if self.rest().starts_with(['+', '-']) {
+            self.pos += 1;
+        }
+        let digits = self.take_while(|c| c.is_ascii_digit());
+        let number_str = &self.text[start..self.pos];
+        if digits.is_empty() {
+            self.pos = start;
+            return Err(self.error("integer"));
+        }
+        match number_str.trim_start_matches('+').parse() {
+            Ok(n) => return Ok(n),
+            Err(_) => return Err(self.error_at(column, "integer in range", format!("{:?}", number_str))),
+        }
+    }
+
+    /// Succeeds if only whitespace is left.
+    pub fn end(&mut self) -> Result<(), ParseError> {
+        self.skip_whitespace();
+        if self.rest().is_empty() {
+            return Ok(());
+        }
+        return Err(self.error("end of line"));
+    }
+}
@@ -0,0 +1,109 @@
+//! Regression suite comparing the solvers against the known answers from
+//! `answers.toml`, for the inputs stored locally in `inputs/` and for the
+//! synthetic benchmark inputs.
+
+#![allow(clippy::needless_return)]
+
+use std::fs;
+use std::path::Path;
+
+const ANSWERS_PATH: &str = "answers.toml";
+const INPUTS_DIR: &str = "inputs";
+
+fn load_answers() -> toml::Table {
+    let path = Path::new(env!("CARGO_MANIFEST_DIR")).join(ANSWERS_PATH);
+    let content = fs::read_to_string(&path)
+        .unwrap_or_else(|err| panic!("cannot read {}: {}", path.display(), err));
+    return content.parse()
+        .unwrap_or_else(|err| panic!("invalid {}: {}", path.display(), err));
+}
+
+fn expected_answer(answers: &toml::Table, day: u32, part: u32) -> Option<String> {
+    let day_answers = answers.get(&format!("day{:02}", day))?.as_table()?;
+    match day_answers.get(&format!("part{}", part))? {
+        toml::Value::String(s) => Some(s.trim_end().to_string()),
+        toml::Value::Integer(n) => Some(n.to_string()),
+        other => panic!("day {} part {}: unsupported answer {}", day, part, other),
+    }
+}
+
+fn read_input(day: u32) -> Option<String> {
+    let path = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join(INPUTS_DIR)
+        .join(format!("day{:02}.txt", day));
+    return fs::read_to_string(path).ok();
+}
+
+/// Failure message if the answer to given input differs from the expected
+/// one.
+fn check(day: u32, part: u32, input: &str, expected: &str) -> Option<String> {
+    match aoc2018::solve(day, part, input) {
+        Ok(answer) => {
+            let actual = answer.to_string();
+            if actual != expected {
+                return Some(format!("day {} part {}: expected {:?}, got {:?}", day, part, expected, actual));
+            }
+            return None;
+        },
+        Err(err) => return Some(format!("day {} part {}: {}", day, part, err)),
+    }
+}
+
+#[test]
+fn known_answers() {
+    let answers = load_answers();
+    let mut failures = Vec::new();
+    let mut checked = 0;
+    let mut skipped = 0;
+
+    for day in 1..=25 {
+        let input_opt = read_input(day);
+        for part in 1..=2 {
+            let (input, expected) = match (&input_opt, expected_answer(&answers, day, part)) {
+                (Some(input), Some(expected)) => (input, expected),
+                (None, Some(_)) => {
+                    eprintln!("day {} part {}: skipped, no input", day, part);
+                    skipped += 1;
+                    continue;
+                },
+                (_, None) if aoc2018::IMPLEMENTED_PARTS.contains(&(day, part)) => {
+                    eprintln!("day {} part {}: skipped, no answer", day, part);
+                    skipped += 1;
+                    continue;
+                },
+                _ => continue,
+            };
+            checked += 1;
+            failures.extend(check(day, part, input, &expected));
+        }
+    }
+
+    eprintln!("checked {} known answers, skipped {} parts", checked, skipped);
+    if checked == 0 {
+        let inputs_dir = Path::new(env!("CARGO_MANIFEST_DIR")).join(INPUTS_DIR);
+        assert!(!inputs_dir.exists(), "{} exists, but none of its inputs has an answer in {}", inputs_dir.display(), ANSWERS_PATH);
+        eprintln!("WARNING: no known answers checked; put the inputs into {}/ and their answers into {}", INPUTS_DIR, ANSWERS_PATH);
+    }
+    assert!(failures.is_empty(), "wrong answers:\n{}", failures.join("\n"));
+}
+
+#[test]
+fn synthetic_answers() {
+    let answers = load_answers();
+    let synthetic = answers.get("synthetic").and_then(|s| s.as_table())
+        .unwrap_or_else(|| panic!("no synthetic answers in {}", ANSWERS_PATH));
+    let mut failures = Vec::new();
+
+    for &(day, part) in aoc2018::IMPLEMENTED_PARTS {
+        let input = match aoc2018::bench::synthetic_input(day) {
+            Some(input) => input,
+            None => continue,
+        };
+        match expected_answer(synthetic, day, part) {
+            Some(expected) => failures.extend(check(day, part, &input, &expected)),
+            None => failures.push(format!("day {} part {}: no synthetic answer", day, part)),
+        }
+    }
+
+    assert!(failures.is_empty(), "wrong synthetic answers:\n{}", failures.join("\n"));
+}